@@ -1,15 +1,21 @@
 // Copyright 2021-2025 Sebastian Ramacher
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use core::{cmp::min, marker::PhantomData};
+use core::{
+    cmp::min,
+    marker::PhantomData,
+    ops::{Add, Div},
+};
 
+#[cfg(feature = "legacy")]
+use aead::consts::U20;
 use aead::{
     Error,
     array::{
         Array, ArraySize,
-        typenum::{IsGreaterOrEqual, IsLessOrEqual, Unsigned},
+        typenum::{IsGreaterOrEqual, IsLessOrEqual, Quot, Sum, Unsigned},
     },
-    consts::{True, U4, U16},
+    consts::{True, U4, U7, U8, U16, U32, U128},
     inout::InOutBuf,
 };
 use ascon_core::State;
@@ -43,23 +49,133 @@ fn u64_from_bytes_partial(input: &[u8]) -> u64 {
     u64::from_le_bytes(tmp)
 }
 
+/// Helper trait for handling the byte order of an Ascon instance
+///
+/// SP 800-232 specifies Ascon in little-endian byte order, whereas Ascon v1.2 used big-endian
+/// byte order. For internal use-only.
+pub(crate) trait ByteOrder {
+    /// Mask for the domain separation between associated data and message
+    const DOMAIN_SEPARATOR: u64;
+
+    /// Load a word from 8 bytes.
+    fn load(input: &[u8]) -> u64;
+    /// Load a word from less than 8 bytes.
+    fn load_partial(input: &[u8]) -> u64;
+    /// Store a word as bytes.
+    fn store(word: u64) -> [u8; 8];
+    /// Produce mask for padding.
+    fn pad(n: usize) -> u64;
+    /// Clear bytes from a 64 bit word.
+    fn clear(word: u64, n: usize) -> u64;
+}
+
+/// Little-endian byte order as used by SP 800-232
+#[derive(Clone)]
+pub(crate) struct LittleEndian;
+
+impl ByteOrder for LittleEndian {
+    const DOMAIN_SEPARATOR: u64 = 0x8000000000000000;
+
+    #[inline(always)]
+    fn load(input: &[u8]) -> u64 {
+        u64_from_bytes(input)
+    }
+
+    #[inline(always)]
+    fn load_partial(input: &[u8]) -> u64 {
+        u64_from_bytes_partial(input)
+    }
+
+    #[inline(always)]
+    fn store(word: u64) -> [u8; 8] {
+        word.to_le_bytes()
+    }
+
+    #[inline(always)]
+    fn pad(n: usize) -> u64 {
+        pad(n)
+    }
+
+    #[inline(always)]
+    fn clear(word: u64, n: usize) -> u64 {
+        clear(word, n)
+    }
+}
+
+/// Big-endian byte order as used by Ascon v1.2
+#[cfg(feature = "legacy")]
+#[derive(Clone)]
+pub(crate) struct BigEndian;
+
+#[cfg(feature = "legacy")]
+impl ByteOrder for BigEndian {
+    const DOMAIN_SEPARATOR: u64 = 0x1;
+
+    #[inline(always)]
+    fn load(input: &[u8]) -> u64 {
+        // Soundness: function is always called with slices of the correct size
+        u64::from_be_bytes(input.try_into().unwrap())
+    }
+
+    #[inline(always)]
+    fn load_partial(input: &[u8]) -> u64 {
+        let mut tmp = [0u8; 8];
+        tmp[0..input.len()].copy_from_slice(input);
+        u64::from_be_bytes(tmp)
+    }
+
+    #[inline(always)]
+    fn store(word: u64) -> [u8; 8] {
+        word.to_be_bytes()
+    }
+
+    #[inline(always)]
+    fn pad(n: usize) -> u64 {
+        0x80_u64 << (56 - 8 * n)
+    }
+
+    #[inline(always)]
+    fn clear(word: u64, n: usize) -> u64 {
+        word & (0xffffffffffffffff >> (n * 8))
+    }
+}
+
 /// Helper trait for handling differences in key usage of Ascon-128
 ///
 /// For internal use-only.
 pub(crate) trait InternalKey<KS: ArraySize>:
     Sized + Clone + for<'a> From<&'a Array<u8, KS>>
 {
+    /// Return K0.
+    ///
+    /// Only keys larger than 128 bits have a non-zero K0.
+    #[inline(always)]
+    fn get_k0(&self) -> u64 {
+        0
+    }
     /// Return K1.
     fn get_k1(&self) -> u64;
     /// Return K2.
     fn get_k2(&self) -> u64;
+    /// Return the mask applied to the nonce.
+    ///
+    /// Only keys of the nonce-masking variant have a non-zero mask.
+    #[inline(always)]
+    fn get_nonce_mask(&self) -> [u64; 2] {
+        [0, 0]
+    }
+    /// Return the key words absorbed into the capacity before finalization.
+    #[inline(always)]
+    fn get_finalization_key(&self) -> [u64; 3] {
+        [self.get_k1(), self.get_k2(), 0]
+    }
 }
 
 #[derive(Clone)]
 #[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
-pub(crate) struct InternalKey16(u64, u64);
+pub(crate) struct InternalKey16<B: ByteOrder = LittleEndian>(u64, u64, PhantomData<B>);
 
-impl InternalKey<U16> for InternalKey16 {
+impl<B: ByteOrder + Clone> InternalKey<U16> for InternalKey16<B> {
     #[inline(always)]
     fn get_k1(&self) -> u64 {
         self.0
@@ -71,9 +187,89 @@ impl InternalKey<U16> for InternalKey16 {
     }
 }
 
-impl From<&Array<u8, U16>> for InternalKey16 {
+impl<B: ByteOrder> From<&Array<u8, U16>> for InternalKey16<B> {
     fn from(key: &Array<u8, U16>) -> Self {
-        Self(u64_from_bytes(&key[..8]), u64_from_bytes(&key[8..]))
+        Self(B::load(&key[..8]), B::load(&key[8..]), PhantomData)
+    }
+}
+
+/// Key of the nonce-masking variant of Ascon-AEAD128
+///
+/// The first 128 bits are used as the key of Ascon-AEAD128, the remaining 128 bits mask the
+/// nonce.
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
+pub(crate) struct InternalKey32(u64, u64, u64, u64);
+
+impl InternalKey<U32> for InternalKey32 {
+    #[inline(always)]
+    fn get_k1(&self) -> u64 {
+        self.0
+    }
+
+    #[inline(always)]
+    fn get_k2(&self) -> u64 {
+        self.1
+    }
+
+    #[inline(always)]
+    fn get_nonce_mask(&self) -> [u64; 2] {
+        [self.2, self.3]
+    }
+}
+
+impl From<&Array<u8, U32>> for InternalKey32 {
+    fn from(key: &Array<u8, U32>) -> Self {
+        Self(
+            u64_from_bytes(&key[..8]),
+            u64_from_bytes(&key[8..16]),
+            u64_from_bytes(&key[16..24]),
+            u64_from_bytes(&key[24..]),
+        )
+    }
+}
+
+/// Key of Ascon-80pq
+#[cfg(feature = "legacy")]
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
+pub(crate) struct InternalKey20(u64, u64, u64);
+
+#[cfg(feature = "legacy")]
+impl InternalKey<U20> for InternalKey20 {
+    #[inline(always)]
+    fn get_k0(&self) -> u64 {
+        self.0
+    }
+
+    #[inline(always)]
+    fn get_k1(&self) -> u64 {
+        self.1
+    }
+
+    #[inline(always)]
+    fn get_k2(&self) -> u64 {
+        self.2
+    }
+
+    #[inline(always)]
+    fn get_finalization_key(&self) -> [u64; 3] {
+        [
+            (self.0 << 32) | (self.1 >> 32),
+            (self.1 << 32) | (self.2 >> 32),
+            self.2 << 32,
+        ]
+    }
+}
+
+#[cfg(feature = "legacy")]
+impl From<&Array<u8, U20>> for InternalKey20 {
+    fn from(key: &Array<u8, U20>) -> Self {
+        Self(
+            BigEndian::load_partial(&key[..4]) >> 32,
+            BigEndian::load(&key[4..12]),
+            BigEndian::load(&key[12..]),
+        )
     }
 }
 
@@ -93,14 +289,54 @@ pub(crate) trait Parameters {
     ///
     /// For internal use-only.
     type InternalKey: InternalKey<Self::KeySize>;
+    /// Byte order used to load and store words
+    ///
+    /// For internal use-only.
+    type ByteOrder: ByteOrder;
+    /// Size of the rate (8 or 16 bytes)
+    ///
+    /// For internal use-only.
+    type Rate: ArraySize;
 
     /// Initialization vector used to initialize Ascon's state
     ///
     /// For internal use-only
     const IV: u64;
+    /// Number of rounds of the permutation used while processing data (6 or 8)
+    ///
+    /// For internal use-only
+    const ROUNDS: usize;
+    /// Number of significant bits of the tag
+    ///
+    /// For internal use-only
+    const TAG_BITS: usize = Self::TagSize::USIZE * 8;
 }
 
-/// Parameters for Ascon-128
+/// Tag lengths in bits
+///
+/// Implemented for all type-level integers from 32 to 128. The tag is stored in the smallest
+/// number of bytes that can hold the given number of bits.
+pub trait TagBits: Unsigned {
+    /// Size of the tag in bytes
+    type TagSize: ArraySize
+        + IsLessOrEqual<U16, Output = True>
+        + IsGreaterOrEqual<U4, Output = True>;
+}
+
+impl<Bits> TagBits for Bits
+where
+    Bits: Unsigned
+        + IsGreaterOrEqual<U32, Output = True>
+        + IsLessOrEqual<U128, Output = True>
+        + Add<U7>,
+    Sum<Bits, U7>: Div<U8>,
+    Quot<Sum<Bits, U7>, U8>:
+        ArraySize + IsLessOrEqual<U16, Output = True> + IsGreaterOrEqual<U4, Output = True>,
+{
+    type TagSize = Quot<Sum<Bits, U7>, U8>;
+}
+
+/// Parameters for Ascon-AEAD128
 pub(crate) struct Parameters128<TagSize>(PhantomData<TagSize>)
 where
     TagSize: ArraySize + IsLessOrEqual<U16, Output = True> + IsGreaterOrEqual<U4, Output = True>;
@@ -112,33 +348,135 @@ where
     type KeySize = U16;
     type TagSize = TagSize;
     type InternalKey = InternalKey16;
+    type ByteOrder = LittleEndian;
+    type Rate = U16;
 
     const IV: u64 = 0x00001000808c0001;
+    const ROUNDS: usize = 8;
+}
+
+/// Parameters for Ascon-AEAD128 with tags truncated to a number of bits
+pub(crate) struct Parameters128Bits<Bits: TagBits>(PhantomData<Bits>);
+
+impl<Bits: TagBits> Parameters for Parameters128Bits<Bits> {
+    type KeySize = U16;
+    type TagSize = Bits::TagSize;
+    type InternalKey = InternalKey16;
+    type ByteOrder = LittleEndian;
+    type Rate = U16;
+
+    const IV: u64 = 0x00001000808c0001;
+    const ROUNDS: usize = 8;
+    const TAG_BITS: usize = Bits::USIZE;
+}
+
+/// Parameters for the nonce-masking variant of Ascon-AEAD128
+pub(crate) struct Parameters128NonceMasked;
+
+impl Parameters for Parameters128NonceMasked {
+    type KeySize = U32;
+    type TagSize = U16;
+    type InternalKey = InternalKey32;
+    type ByteOrder = LittleEndian;
+    type Rate = U16;
+
+    const IV: u64 = 0x00001000808c0001;
+    const ROUNDS: usize = 8;
+}
+
+/// Parameters for Ascon-128 (v1.2)
+#[cfg(feature = "legacy")]
+pub(crate) struct ParametersAscon128;
+
+#[cfg(feature = "legacy")]
+impl Parameters for ParametersAscon128 {
+    type KeySize = U16;
+    type TagSize = U16;
+    type InternalKey = InternalKey16<BigEndian>;
+    type ByteOrder = BigEndian;
+    type Rate = U8;
+
+    const IV: u64 = 0x80400c0600000000;
+    const ROUNDS: usize = 6;
+}
+
+/// Parameters for Ascon-128a (v1.2)
+#[cfg(feature = "legacy")]
+pub(crate) struct ParametersAscon128a;
+
+#[cfg(feature = "legacy")]
+impl Parameters for ParametersAscon128a {
+    type KeySize = U16;
+    type TagSize = U16;
+    type InternalKey = InternalKey16<BigEndian>;
+    type ByteOrder = BigEndian;
+    type Rate = U16;
+
+    const IV: u64 = 0x80800c0800000000;
+    const ROUNDS: usize = 8;
+}
+
+/// Parameters for Ascon-80pq (v1.2)
+#[cfg(feature = "legacy")]
+pub(crate) struct ParametersAscon80pq;
+
+#[cfg(feature = "legacy")]
+impl Parameters for ParametersAscon80pq {
+    type KeySize = U20;
+    type TagSize = U16;
+    type InternalKey = InternalKey20;
+    type ByteOrder = BigEndian;
+    type Rate = U8;
+
+    const IV: u64 = 0xa0400c0600000000;
+    const ROUNDS: usize = 6;
+}
+
+/// Clear the insignificant bits of the last byte of a tag.
+#[inline(always)]
+fn mask_tag<P: Parameters>(tag: &mut Array<u8, P::TagSize>) {
+    let bits = P::TAG_BITS % 8;
+    if bits != 0 {
+        tag[P::TagSize::USIZE - 1] &= (1 << bits) - 1;
+    }
+}
+
+/// Compare the significant bits of two tags in constant time.
+#[inline(always)]
+fn verify_tag<P: Parameters>(
+    tag: &Array<u8, P::TagSize>,
+    expected_tag: &Array<u8, P::TagSize>,
+) -> bool {
+    let mut expected_tag = expected_tag.clone();
+    mask_tag::<P>(&mut expected_tag);
+    bool::from(tag.ct_eq(&expected_tag))
 }
 
 /// Core implementation of Ascon for one encryption/decryption operation
-pub(crate) struct AsconCore<'a, P: Parameters> {
+pub(crate) struct AsconCore<P: Parameters> {
     state: State,
-    key: &'a P::InternalKey,
+    key: P::InternalKey,
 }
 
-impl<'a, P: Parameters> AsconCore<'a, P> {
-    pub(crate) fn new(internal_key: &'a P::InternalKey, nonce: &Array<u8, U16>) -> Self {
+impl<P: Parameters> AsconCore<P> {
+    pub(crate) fn new(internal_key: &P::InternalKey, nonce: &Array<u8, U16>) -> Self {
+        let nonce_mask = internal_key.get_nonce_mask();
         let mut state = State::new(
-            P::IV,
+            P::IV ^ internal_key.get_k0(),
             internal_key.get_k1(),
             internal_key.get_k2(),
-            u64_from_bytes(&nonce[..8]),
-            u64_from_bytes(&nonce[8..]),
+            P::ByteOrder::load(&nonce[..8]) ^ nonce_mask[0],
+            P::ByteOrder::load(&nonce[8..]) ^ nonce_mask[1],
         );
 
         state.permute_12();
+        state[2] ^= internal_key.get_k0();
         state[3] ^= internal_key.get_k1();
         state[4] ^= internal_key.get_k2();
 
         Self {
             state,
-            key: internal_key,
+            key: internal_key.clone(),
         }
     }
 
@@ -149,123 +487,243 @@ impl<'a, P: Parameters> AsconCore<'a, P> {
         self.state[4] ^= self.key.get_k2();
     }
 
-    /// Permutation with 8 rounds based on the parameters
+    /// Permutation with 6 or 8 rounds based on the parameters
     #[inline(always)]
     fn permute_state(&mut self) {
+        #[cfg(feature = "legacy")]
+        if P::ROUNDS == 6 {
+            self.state.permute_6();
+            return;
+        }
+        debug_assert_eq!(P::ROUNDS, 8);
         self.state.permute_8();
     }
 
+    /// Return the rate part of the state as bytes.
+    #[inline(always)]
+    fn rate_bytes(&self) -> [u8; 16] {
+        let mut block = [0u8; 16];
+        for (i, word) in block[..P::Rate::USIZE].chunks_exact_mut(8).enumerate() {
+            word.copy_from_slice(&P::ByteOrder::store(self.state[i]));
+        }
+        block
+    }
+
+    /// Replace the rate part of the state.
+    #[inline(always)]
+    fn set_rate_bytes(&mut self, block: &[u8; 16]) {
+        for (i, word) in block[..P::Rate::USIZE].chunks_exact(8).enumerate() {
+            self.state[i] = P::ByteOrder::load(word);
+        }
+    }
+
+    /// Absorb a partial block starting at byte `pos` of the rate.
+    fn absorb_partial(&mut self, pos: usize, data: &[u8]) {
+        let mut block = self.rate_bytes();
+        block[pos..pos + data.len()]
+            .iter_mut()
+            .zip(data)
+            .for_each(|(s, d)| *s ^= d);
+        self.set_rate_bytes(&block);
+    }
+
+    /// Encrypt a partial block starting at byte `pos` of the rate.
+    fn encrypt_partial(&mut self, pos: usize, mut message: InOutBuf<'_, '_, u8>) {
+        let len = message.len();
+        let mut block = self.rate_bytes();
+        message.xor_in2out(&block[pos..pos + len]);
+        block[pos..pos + len].copy_from_slice(message.get_out());
+        self.set_rate_bytes(&block);
+    }
+
+    /// Decrypt a partial block starting at byte `pos` of the rate.
+    fn decrypt_partial(&mut self, pos: usize, mut ciphertext: InOutBuf<'_, '_, u8>) {
+        let len = ciphertext.len();
+        let mut block = self.rate_bytes();
+        let mut cx = [0u8; 16];
+        cx[..len].copy_from_slice(ciphertext.get_in());
+        ciphertext.xor_in2out(&block[pos..pos + len]);
+        block[pos..pos + len].copy_from_slice(&cx[..len]);
+        self.set_rate_bytes(&block);
+    }
+
+    /// Apply padding at byte `pos` of the rate.
+    #[inline(always)]
+    fn pad_at(&mut self, pos: usize) {
+        self.state[pos / 8] ^= P::ByteOrder::pad(pos % 8);
+    }
+
+    /// Apply domain separation between associated data and message.
+    #[inline(always)]
+    fn separate_domains(&mut self) {
+        self.state[4] ^= P::ByteOrder::DOMAIN_SEPARATOR;
+    }
+
+    fn process_associated_data_blocks(&mut self, associated_data: &[u8]) -> usize {
+        // TODO: replace with as_chunks once stabilized
+        // https://github.com/rust-lang/rust/issues/74985
+
+        let blocks = associated_data.chunks_exact(P::Rate::USIZE);
+        let processed = associated_data.len() - blocks.remainder().len();
+        for block in blocks {
+            // process full block of associated data
+            for (i, word) in block.chunks_exact(8).enumerate() {
+                self.state[i] ^= P::ByteOrder::load(word);
+            }
+            self.permute_state();
+        }
+        processed
+    }
+
     fn process_associated_data(&mut self, associated_data: &[u8]) {
         if !associated_data.is_empty() {
-            // TODO: replace with as_chunks once stabilized
-            // https://github.com/rust-lang/rust/issues/74985
-
-            let mut blocks = associated_data.chunks_exact(16);
-            for block in blocks.by_ref() {
-                // process full block of associated data
-                self.state[0] ^= u64_from_bytes(&block[..8]);
-                self.state[1] ^= u64_from_bytes(&block[8..16]);
-                self.permute_state();
-            }
+            let processed = self.process_associated_data_blocks(associated_data);
 
             // process partial block if it exists
-            let mut last_block = blocks.remainder();
+            let mut last_block = &associated_data[processed..];
             let sidx = if last_block.len() >= 8 {
-                self.state[0] ^= u64_from_bytes(&last_block[..8]);
+                self.state[0] ^= P::ByteOrder::load(&last_block[..8]);
                 last_block = &last_block[8..];
                 1
             } else {
                 0
             };
-            self.state[sidx] ^= pad(last_block.len());
+            self.state[sidx] ^= P::ByteOrder::pad(last_block.len());
             if !last_block.is_empty() {
-                self.state[sidx] ^= u64_from_bytes_partial(last_block);
+                self.state[sidx] ^= P::ByteOrder::load_partial(last_block);
             }
             self.permute_state();
         }
 
-        // domain separation
-        self.state[4] ^= 0x8000000000000000;
+        self.separate_domains();
     }
 
-    fn process_encrypt_inout(&mut self, message: InOutBuf<'_, '_, u8>) {
-        let (blocks, mut last_block) = message.into_chunks::<U16>();
+    fn process_encrypt_inout_blocks<'inp, 'out>(
+        &mut self,
+        message: InOutBuf<'inp, 'out, u8>,
+    ) -> InOutBuf<'inp, 'out, u8> {
+        let (blocks, last_block) = message.into_chunks::<P::Rate>();
 
         for mut block in blocks {
             // process full block of message
-            self.state[0] ^= u64_from_bytes(&block.get_in()[..8]);
-            block.get_out()[..8].copy_from_slice(&u64::to_le_bytes(self.state[0]));
-            self.state[1] ^= u64_from_bytes(&block.get_in()[8..16]);
-            block.get_out()[8..16].copy_from_slice(&u64::to_le_bytes(self.state[1]));
+            for i in 0..P::Rate::USIZE / 8 {
+                let range = 8 * i..8 * (i + 1);
+                self.state[i] ^= P::ByteOrder::load(&block.get_in()[range.clone()]);
+                block.get_out()[range].copy_from_slice(&P::ByteOrder::store(self.state[i]));
+            }
             self.permute_state();
         }
+        last_block
+    }
+
+    fn process_encrypt_inout(&mut self, message: InOutBuf<'_, '_, u8>) {
+        let mut last_block = self.process_encrypt_inout_blocks(message);
 
         // process partial block if it exists
         let sidx = if last_block.len() >= 8 {
-            self.state[0] ^= u64_from_bytes(&last_block.get_in()[..8]);
-            last_block.get_out()[..8].copy_from_slice(&u64::to_le_bytes(self.state[0]));
+            self.state[0] ^= P::ByteOrder::load(&last_block.get_in()[..8]);
+            last_block.get_out()[..8].copy_from_slice(&P::ByteOrder::store(self.state[0]));
             (_, last_block) = last_block.split_at(8);
             1
         } else {
             0
         };
-        self.state[sidx] ^= pad(last_block.len());
+        self.state[sidx] ^= P::ByteOrder::pad(last_block.len());
         if !last_block.is_empty() {
-            self.state[sidx] ^= u64_from_bytes_partial(last_block.get_in());
+            self.state[sidx] ^= P::ByteOrder::load_partial(last_block.get_in());
             let last_block_len = last_block.len();
             last_block
                 .get_out()
-                .copy_from_slice(&u64::to_le_bytes(self.state[sidx])[0..last_block_len]);
+                .copy_from_slice(&P::ByteOrder::store(self.state[sidx])[0..last_block_len]);
         }
     }
 
-    fn process_decrypt_inout(&mut self, ciphertext: InOutBuf<'_, '_, u8>) {
-        let (blocks, mut last_block) = ciphertext.into_chunks::<U16>();
+    fn process_decrypt_inout_blocks<'inp, 'out>(
+        &mut self,
+        ciphertext: InOutBuf<'inp, 'out, u8>,
+    ) -> InOutBuf<'inp, 'out, u8> {
+        let (blocks, last_block) = ciphertext.into_chunks::<P::Rate>();
         for mut block in blocks {
             // process full block of ciphertext
-            let cx = u64_from_bytes(&block.get_in()[..8]);
-            block.get_out()[..8].copy_from_slice(&u64::to_le_bytes(self.state[0] ^ cx));
-            self.state[0] = cx;
-            let cx = u64_from_bytes(&block.get_in()[8..16]);
-            block.get_out()[8..16].copy_from_slice(&u64::to_le_bytes(self.state[1] ^ cx));
-            self.state[1] = cx;
+            for i in 0..P::Rate::USIZE / 8 {
+                let range = 8 * i..8 * (i + 1);
+                let cx = P::ByteOrder::load(&block.get_in()[range.clone()]);
+                block.get_out()[range].copy_from_slice(&P::ByteOrder::store(self.state[i] ^ cx));
+                self.state[i] = cx;
+            }
             self.permute_state();
         }
+        last_block
+    }
+
+    fn process_decrypt_inout(&mut self, ciphertext: InOutBuf<'_, '_, u8>) {
+        let mut last_block = self.process_decrypt_inout_blocks(ciphertext);
 
         // process partial block if it exists
         let sidx = if last_block.len() >= 8 {
-            let cx = u64_from_bytes(&last_block.get_in()[..8]);
-            last_block.get_out()[..8].copy_from_slice(&u64::to_le_bytes(self.state[0] ^ cx));
+            let cx = P::ByteOrder::load(&last_block.get_in()[..8]);
+            last_block.get_out()[..8].copy_from_slice(&P::ByteOrder::store(self.state[0] ^ cx));
             self.state[0] = cx;
             (_, last_block) = last_block.split_at(8);
             1
         } else {
             0
         };
-        self.state[sidx] ^= pad(last_block.len());
+        self.state[sidx] ^= P::ByteOrder::pad(last_block.len());
         if !last_block.is_empty() {
-            let cx = u64_from_bytes_partial(last_block.get_in());
+            let cx = P::ByteOrder::load_partial(last_block.get_in());
             self.state[sidx] ^= cx;
             let last_block_len = last_block.len();
             last_block
                 .get_out()
-                .copy_from_slice(&u64::to_le_bytes(self.state[sidx])[0..last_block_len]);
-            self.state[sidx] = clear(self.state[sidx], last_block.len()) ^ cx;
+                .copy_from_slice(&P::ByteOrder::store(self.state[sidx])[0..last_block_len]);
+            self.state[sidx] = P::ByteOrder::clear(self.state[sidx], last_block.len()) ^ cx;
+        }
+    }
+
+    /// Absorb ciphertext into the state as decryption would, but without producing plaintext.
+    fn process_absorb_ciphertext(&mut self, ciphertext: &[u8]) {
+        let mut blocks = ciphertext.chunks_exact(P::Rate::USIZE);
+        for block in blocks.by_ref() {
+            // process full block of ciphertext
+            for (i, word) in block.chunks_exact(8).enumerate() {
+                self.state[i] = P::ByteOrder::load(word);
+            }
+            self.permute_state();
+        }
+
+        // process partial block if it exists
+        let mut last_block = blocks.remainder();
+        let sidx = if last_block.len() >= 8 {
+            self.state[0] = P::ByteOrder::load(&last_block[..8]);
+            last_block = &last_block[8..];
+            1
+        } else {
+            0
+        };
+        self.state[sidx] ^= P::ByteOrder::pad(last_block.len());
+        if !last_block.is_empty() {
+            let cx = P::ByteOrder::load_partial(last_block);
+            self.state[sidx] = P::ByteOrder::clear(self.state[sidx], last_block.len()) ^ cx;
         }
     }
 
     fn process_final(&mut self) -> Array<u8, P::TagSize> {
-        self.state[2] ^= self.key.get_k1();
-        self.state[3] ^= self.key.get_k2();
+        let rate_words = P::Rate::USIZE / 8;
+        for (i, k) in self.key.get_finalization_key().into_iter().enumerate() {
+            self.state[rate_words + i] ^= k;
+        }
         self.permute_12_and_apply_key();
 
         let mut tag = Array::default();
         tag[..min(8, P::TagSize::USIZE)]
-            .copy_from_slice(&u64::to_le_bytes(self.state[3])[..min(8, P::TagSize::USIZE)]);
+            .copy_from_slice(&P::ByteOrder::store(self.state[3])[..min(8, P::TagSize::USIZE)]);
         if P::TagSize::USIZE > 8 {
-            tag[8..min(16, P::TagSize::USIZE)]
-                .copy_from_slice(&u64::to_le_bytes(self.state[4])[..min(8, P::TagSize::USIZE - 8)]);
+            tag[8..min(16, P::TagSize::USIZE)].copy_from_slice(
+                &P::ByteOrder::store(self.state[4])[..min(8, P::TagSize::USIZE - 8)],
+            );
         }
+        mask_tag::<P>(&mut tag);
         tag
     }
 
@@ -279,6 +737,24 @@ impl<'a, P: Parameters> AsconCore<'a, P> {
         self.process_final()
     }
 
+    /// Encrypt without associated data and without producing a tag.
+    ///
+    /// Only for constructions that authenticate the message by other means.
+    #[cfg(feature = "siv")]
+    pub(crate) fn encrypt_inout_untagged(&mut self, message: InOutBuf<'_, '_, u8>) {
+        self.separate_domains();
+        self.process_encrypt_inout(message);
+    }
+
+    /// Decrypt without associated data and without verifying a tag.
+    ///
+    /// Only for constructions that authenticate the message by other means.
+    #[cfg(feature = "siv")]
+    pub(crate) fn decrypt_inout_untagged(&mut self, ciphertext: InOutBuf<'_, '_, u8>) {
+        self.separate_domains();
+        self.process_decrypt_inout(ciphertext);
+    }
+
     pub(crate) fn decrypt_inout(
         &mut self,
         mut ciphertext: InOutBuf<'_, '_, u8>,
@@ -289,7 +765,72 @@ impl<'a, P: Parameters> AsconCore<'a, P> {
         self.process_decrypt_inout(ciphertext.reborrow());
 
         let tag = self.process_final();
-        if bool::from(tag.ct_eq(expected_tag)) {
+        if verify_tag::<P>(&tag, expected_tag) {
+            Ok(())
+        } else {
+            ciphertext.get_out().fill(0);
+            Err(Error)
+        }
+    }
+
+    /// Verify the tag before decrypting.
+    ///
+    /// The ciphertext is absorbed and the tag is verified in a first pass. Only if the tag is
+    /// valid, the ciphertext is decrypted in a second pass. Hence, the output buffer is never
+    /// written to if verification fails.
+    pub(crate) fn decrypt_inout_verify_first(
+        &mut self,
+        ciphertext: InOutBuf<'_, '_, u8>,
+        associated_data: &[u8],
+        expected_tag: &Array<u8, P::TagSize>,
+    ) -> Result<(), Error> {
+        self.process_associated_data(associated_data);
+        let state = self.state.clone();
+        self.process_absorb_ciphertext(ciphertext.get_in());
+
+        let tag = self.process_final();
+        if !verify_tag::<P>(&tag, expected_tag) {
+            return Err(Error);
+        }
+
+        self.state = state;
+        self.process_decrypt_inout(ciphertext);
+        Ok(())
+    }
+
+    /// Verify the tag of a ciphertext without decrypting it.
+    pub(crate) fn verify(
+        &mut self,
+        ciphertext: &[u8],
+        associated_data: &[u8],
+        expected_tag: &Array<u8, P::TagSize>,
+    ) -> Result<(), Error> {
+        self.process_associated_data(associated_data);
+        self.process_absorb_ciphertext(ciphertext);
+
+        let tag = self.process_final();
+        if verify_tag::<P>(&tag, expected_tag) {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Decrypt and verify a tag truncated to a length chosen at runtime.
+    ///
+    /// The tag is compared against the first `expected_tag.len()` bytes of the full tag.
+    pub(crate) fn decrypt_inout_truncated(
+        &mut self,
+        mut ciphertext: InOutBuf<'_, '_, u8>,
+        associated_data: &[u8],
+        expected_tag: &[u8],
+    ) -> Result<(), Error> {
+        debug_assert!(expected_tag.len() <= P::TagSize::USIZE);
+        self.process_associated_data(associated_data);
+        self.process_decrypt_inout(ciphertext.reborrow());
+
+        let tag = self.process_final();
+        if bool::from(tag[..expected_tag.len()].ct_eq(expected_tag)) {
             Ok(())
         } else {
             ciphertext.get_out().fill(0);
@@ -298,6 +839,145 @@ impl<'a, P: Parameters> AsconCore<'a, P> {
     }
 }
 
+/// Phase of an incremental encryption/decryption operation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Phase {
+    /// Processing associated data (and whether any was absorbed)
+    AssociatedData(bool),
+    /// Processing plaintext or ciphertext
+    Message,
+}
+
+/// Core implementation of Ascon for incremental encryption/decryption operations
+///
+/// Data can be provided in chunks of arbitrary sizes. Partial blocks are directly processed in the
+/// rate part of the state and `pos` keeps track of the number of bytes of the current block.
+pub(crate) struct AsconIncrementalCore<P: Parameters> {
+    core: AsconCore<P>,
+    pos: usize,
+    phase: Phase,
+}
+
+impl<P: Parameters> AsconIncrementalCore<P> {
+    pub(crate) fn new(internal_key: &P::InternalKey, nonce: &Array<u8, U16>) -> Self {
+        Self {
+            core: AsconCore::new(internal_key, nonce),
+            pos: 0,
+            phase: Phase::AssociatedData(false),
+        }
+    }
+
+    pub(crate) fn process_associated_data(
+        &mut self,
+        mut associated_data: &[u8],
+    ) -> Result<(), Error> {
+        let Phase::AssociatedData(absorbed) = &mut self.phase else {
+            return Err(Error);
+        };
+        if associated_data.is_empty() {
+            return Ok(());
+        }
+        *absorbed = true;
+
+        // complete partial block
+        if self.pos != 0 {
+            let len = min(P::Rate::USIZE - self.pos, associated_data.len());
+            self.core.absorb_partial(self.pos, &associated_data[..len]);
+            associated_data = &associated_data[len..];
+            self.pos += len;
+            if self.pos != P::Rate::USIZE {
+                return Ok(());
+            }
+            self.core.permute_state();
+        }
+
+        let processed = self.core.process_associated_data_blocks(associated_data);
+        self.core.absorb_partial(0, &associated_data[processed..]);
+        self.pos = associated_data.len() - processed;
+        Ok(())
+    }
+
+    fn finish_associated_data(&mut self) {
+        if let Phase::AssociatedData(absorbed) = self.phase {
+            if absorbed {
+                self.core.pad_at(self.pos);
+                self.core.permute_state();
+            }
+            self.core.separate_domains();
+            self.pos = 0;
+            self.phase = Phase::Message;
+        }
+    }
+
+    pub(crate) fn encrypt_inout(&mut self, mut message: InOutBuf<'_, '_, u8>) {
+        self.finish_associated_data();
+
+        // complete partial block
+        if self.pos != 0 {
+            let len = min(P::Rate::USIZE - self.pos, message.len());
+            let (head, tail) = message.split_at(len);
+            self.core.encrypt_partial(self.pos, head);
+            message = tail;
+            self.pos += len;
+            if self.pos != P::Rate::USIZE {
+                return;
+            }
+            self.core.permute_state();
+        }
+
+        let last_block = self.core.process_encrypt_inout_blocks(message);
+        self.pos = last_block.len();
+        self.core.encrypt_partial(0, last_block);
+    }
+
+    pub(crate) fn decrypt_inout(&mut self, mut ciphertext: InOutBuf<'_, '_, u8>) {
+        self.finish_associated_data();
+
+        // complete partial block
+        if self.pos != 0 {
+            let len = min(P::Rate::USIZE - self.pos, ciphertext.len());
+            let (head, tail) = ciphertext.split_at(len);
+            self.core.decrypt_partial(self.pos, head);
+            ciphertext = tail;
+            self.pos += len;
+            if self.pos != P::Rate::USIZE {
+                return;
+            }
+            self.core.permute_state();
+        }
+
+        let last_block = self.core.process_decrypt_inout_blocks(ciphertext);
+        self.pos = last_block.len();
+        self.core.decrypt_partial(0, last_block);
+    }
+
+    pub(crate) fn finalize(mut self) -> Array<u8, P::TagSize> {
+        self.finish_associated_data();
+        self.core.pad_at(self.pos);
+        self.core.process_final()
+    }
+
+    pub(crate) fn verify(self, expected_tag: &Array<u8, P::TagSize>) -> Result<(), Error> {
+        let tag = self.finalize();
+        if verify_tag::<P>(&tag, expected_tag) {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Verify a tag truncated to a length chosen at runtime.
+    pub(crate) fn verify_truncated(self, expected_tag: &[u8]) -> Result<(), Error> {
+        debug_assert!(expected_tag.len() <= P::TagSize::USIZE);
+        let tag = self.finalize();
+        if bool::from(tag[..expected_tag.len()].ct_eq(expected_tag)) {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -312,4 +992,16 @@ mod test {
         assert_eq!(clear(0x0123456789abcdef, 6), 0x0123000000000000);
         assert_eq!(clear(0x0123456789abcdef, 7), 0x0100000000000000);
     }
+
+    #[cfg(feature = "legacy")]
+    #[test]
+    fn clear_be_0to7() {
+        assert_eq!(BigEndian::clear(0x0123456789abcdef, 1), 0x0023456789abcdef);
+        assert_eq!(BigEndian::clear(0x0123456789abcdef, 2), 0x0000456789abcdef);
+        assert_eq!(BigEndian::clear(0x0123456789abcdef, 3), 0x0000006789abcdef);
+        assert_eq!(BigEndian::clear(0x0123456789abcdef, 4), 0x0000000089abcdef);
+        assert_eq!(BigEndian::clear(0x0123456789abcdef, 5), 0x0000000000abcdef);
+        assert_eq!(BigEndian::clear(0x0123456789abcdef, 6), 0x000000000000cdef);
+        assert_eq!(BigEndian::clear(0x0123456789abcdef, 7), 0x00000000000000ef);
+    }
 }
@@ -0,0 +1,125 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Key-committing Ascon-AEAD128
+
+use aead::{
+    AeadCore, AeadInOut, Error, Key, KeyInit, KeySizeUser, Nonce, Tag, TagPosition,
+    array::{Array, ArraySize},
+    consts::{U16, U32, U48},
+    inout::InOutBuf,
+};
+use ascon_hash::{AsconHash256, Digest};
+use subtle::ConstantTimeEq;
+
+use crate::{Ascon, asconcore::Parameters128};
+
+/// Domain separator of the key commitment
+const COMMITMENT_DOMAIN: &[u8] = b"Ascon-AEAD128 key commitment";
+
+/// AEAD schemes committing to the key
+///
+/// The tag of such schemes contains a commitment to the key. Decryption fails if the ciphertext
+/// was not produced with the key used for decryption, even if an adversary chose both keys.
+pub trait KeyCommittingAead: AeadCore {
+    /// Size of the key commitment contained in the tag
+    type CommitmentSize: ArraySize;
+}
+
+/// Key-committing Ascon-AEAD128
+///
+/// Extends Ascon-AEAD128 by a 256 bit commitment computed as Ascon-Hash256 over a domain
+/// separator, the key and the nonce. The tag consists of the 128 bit tag of Ascon-AEAD128 followed
+/// by the commitment.
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use ascon_aead::{CommittingAsconAead128, CommittingAsconAead128Key, CommittingAsconAead128Nonce};
+/// use ascon_aead::aead::{Aead, KeyInit};
+///
+/// let key = CommittingAsconAead128Key::from_slice(b"very secret key.");
+/// let cipher = CommittingAsconAead128::new(key);
+/// let nonce = CommittingAsconAead128Nonce::from_slice(b"unique nonce 012");
+///
+/// let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref()).unwrap();
+/// let plaintext = cipher.decrypt(nonce, ciphertext.as_ref()).unwrap();
+/// assert_eq!(&plaintext, b"plaintext message");
+///
+/// let other_cipher = CommittingAsconAead128::new_from_slice(b"other secret key").unwrap();
+/// assert!(other_cipher.decrypt(nonce, ciphertext.as_ref()).is_err());
+/// # }
+/// ```
+pub struct CommittingAsconAead128 {
+    aead: Ascon<Parameters128<U16>>,
+    // hash state after absorbing the domain separator and the key
+    commitment: AsconHash256,
+}
+/// Key for key-committing Ascon-AEAD128
+pub type CommittingAsconAead128Key = Key<CommittingAsconAead128>;
+/// Nonce for key-committing Ascon-AEAD128
+pub type CommittingAsconAead128Nonce = Nonce<CommittingAsconAead128>;
+/// Tag for key-committing Ascon-AEAD128
+pub type CommittingAsconAead128Tag = Tag<CommittingAsconAead128>;
+
+impl CommittingAsconAead128 {
+    /// Compute the key commitment for the given nonce.
+    fn commit(&self, nonce: &Nonce<Self>) -> Array<u8, U32> {
+        self.commitment.clone().chain_update(nonce).finalize()
+    }
+}
+
+impl KeySizeUser for CommittingAsconAead128 {
+    type KeySize = U16;
+}
+
+impl KeyInit for CommittingAsconAead128 {
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            aead: Ascon::new(key),
+            commitment: AsconHash256::new()
+                .chain_update(COMMITMENT_DOMAIN)
+                .chain_update(key),
+        }
+    }
+}
+
+impl AeadCore for CommittingAsconAead128 {
+    type NonceSize = U16;
+    type TagSize = U48;
+    const TAG_POSITION: TagPosition = TagPosition::Postfix;
+}
+
+impl KeyCommittingAead for CommittingAsconAead128 {
+    type CommitmentSize = U32;
+}
+
+impl AeadInOut for CommittingAsconAead128 {
+    fn encrypt_inout_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+    ) -> Result<Tag<Self>, Error> {
+        let tag = self
+            .aead
+            .encrypt_inout_detached(nonce, associated_data, buffer)?;
+        Ok(tag.concat(self.commit(nonce)))
+    }
+
+    fn decrypt_inout_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        mut buffer: InOutBuf<'_, '_, u8>,
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        let (tag, commitment) = tag.split_ref::<U16>();
+        if !bool::from(self.commit(nonce).ct_eq(commitment)) {
+            buffer.get_out().fill(0);
+            return Err(Error);
+        }
+
+        self.aead
+            .decrypt_inout_detached(nonce, associated_data, buffer, tag)
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Ascon-AEAD128 with tag lengths selected at runtime
+
+use aead::{Error, KeySizeUser, common::InvalidLength, consts::U16, inout::InOutBuf};
+
+use crate::{
+    AsconAead128Key, AsconAead128Nonce,
+    asconcore::{AsconCore, InternalKey16, Parameters128},
+};
+
+/// Ascon-AEAD128 with a tag length selected at runtime
+///
+/// Tag lengths of 4 to 16 bytes are supported. The ciphertext and tag are the same as produced by
+/// [`TruncatedAsconAead128`](crate::TruncatedAsconAead128) with the same tag length.
+///
+/// ```
+/// use ascon_aead::{AsconAead128Key, AsconAead128Nonce, DynTagAsconAead128};
+///
+/// let key = AsconAead128Key::from_slice(b"very secret key.");
+/// let nonce = AsconAead128Nonce::from_slice(b"unique nonce 012");
+/// let cipher = DynTagAsconAead128::new(key, 8).unwrap();
+///
+/// let mut buffer = *b"plaintext message";
+/// let mut tag = [0u8; 8];
+/// cipher
+///     .encrypt_in_place_detached(nonce, b"associated data", &mut buffer, &mut tag)
+///     .unwrap();
+/// cipher
+///     .decrypt_in_place_detached(nonce, b"associated data", &mut buffer, &tag)
+///     .unwrap();
+/// assert_eq!(&buffer, b"plaintext message");
+/// ```
+#[derive(Clone)]
+pub struct DynTagAsconAead128 {
+    key: InternalKey16,
+    tag_size: usize,
+}
+
+impl DynTagAsconAead128 {
+    /// Minimal supported tag length in bytes
+    pub const MIN_TAG_SIZE: usize = 4;
+    /// Maximal supported tag length in bytes
+    pub const MAX_TAG_SIZE: usize = 16;
+
+    /// Create a new instance with the given key and tag length in bytes.
+    ///
+    /// Fails if the tag length is not between [`Self::MIN_TAG_SIZE`] and [`Self::MAX_TAG_SIZE`].
+    pub fn new(key: &AsconAead128Key, tag_size: usize) -> Result<Self, InvalidLength> {
+        if !(Self::MIN_TAG_SIZE..=Self::MAX_TAG_SIZE).contains(&tag_size) {
+            return Err(InvalidLength);
+        }
+        Ok(Self {
+            key: InternalKey16::from(key),
+            tag_size,
+        })
+    }
+
+    /// Create a new instance from a key slice and tag length in bytes.
+    pub fn new_from_slice(key: &[u8], tag_size: usize) -> Result<Self, InvalidLength> {
+        let key = AsconAead128Key::try_from(key).map_err(|_| InvalidLength)?;
+        Self::new(&key, tag_size)
+    }
+
+    /// Tag length in bytes
+    pub fn tag_size(&self) -> usize {
+        self.tag_size
+    }
+
+    /// Encrypt the data in the provided [`InOutBuf`] and write the tag to `tag`.
+    ///
+    /// Fails if the length of `tag` does not match the configured tag length.
+    pub fn encrypt_inout_detached(
+        &self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+        tag: &mut [u8],
+    ) -> Result<(), Error> {
+        if tag.len() != self.tag_size
+            || (buffer.len() as u64)
+                .checked_add(associated_data.len() as u64)
+                .is_none()
+        {
+            return Err(Error);
+        }
+
+        let mut core = AsconCore::<Parameters128<U16>>::new(&self.key, nonce);
+        let full_tag = core.encrypt_inout(buffer, associated_data);
+        tag.copy_from_slice(&full_tag[..self.tag_size]);
+        Ok(())
+    }
+
+    /// Encrypt the data in-place and write the tag to `tag`.
+    ///
+    /// Fails if the length of `tag` does not match the configured tag length.
+    pub fn encrypt_in_place_detached(
+        &self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &mut [u8],
+    ) -> Result<(), Error> {
+        self.encrypt_inout_detached(nonce, associated_data, buffer.into(), tag)
+    }
+
+    /// Decrypt the data in the provided [`InOutBuf`] and verify `tag`.
+    ///
+    /// Fails if the length of `tag` does not match the configured tag length or if the tag is
+    /// invalid. In the latter case, the output buffer is cleared.
+    pub fn decrypt_inout_detached(
+        &self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        if tag.len() != self.tag_size
+            || (buffer.len() as u64)
+                .checked_add(associated_data.len() as u64)
+                .is_none()
+        {
+            return Err(Error);
+        }
+
+        let mut core = AsconCore::<Parameters128<U16>>::new(&self.key, nonce);
+        core.decrypt_inout_truncated(buffer, associated_data, tag)
+    }
+
+    /// Decrypt the data in-place and verify `tag`.
+    ///
+    /// Fails if the length of `tag` does not match the configured tag length or if the tag is
+    /// invalid. In the latter case, the buffer is cleared.
+    pub fn decrypt_in_place_detached(
+        &self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        self.decrypt_inout_detached(nonce, associated_data, buffer.into(), tag)
+    }
+}
+
+impl KeySizeUser for DynTagAsconAead128 {
+    type KeySize = U16;
+}
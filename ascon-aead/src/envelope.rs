@@ -0,0 +1,399 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Self-describing encrypted envelopes
+//!
+//! An envelope stores everything required to decrypt a message besides the key and the associated
+//! data:
+//!
+//! ```text
+//! envelope = magic || version || algorithm || key ID length || key ID || nonce || ciphertext || tag
+//! ```
+//!
+//! | Field         | Size            | Description                                    |
+//! |---------------|-----------------|------------------------------------------------|
+//! | magic         | 4 bytes         | `ASCN`                                         |
+//! | version       | 1 byte          | format version, currently 1                    |
+//! | algorithm     | 1 byte          | algorithm identifier, see [`Algorithm`]        |
+//! | key ID length | 1 byte          | length of the key ID, 0 if no key ID is given  |
+//! | key ID        | 0 to 255 bytes  | identifier of the key                          |
+//! | nonce         | 16 bytes        | nonce                                          |
+//! | ciphertext    | variable        | ciphertext                                     |
+//! | tag           | 4 to 16 bytes   | tag (size given by the algorithm)              |
+//!
+//! The header, i.e., all fields up to and including the nonce, is authenticated as associated
+//! data. The associated data of the message follows the header.
+//!
+//! ```
+//! # #[cfg(all(feature = "alloc", feature = "getrandom"))] {
+//! use ascon_aead::AsconAead128Key;
+//! use ascon_aead::envelope::{Header, open, seal};
+//!
+//! let key = AsconAead128Key::from_slice(b"very secret key.");
+//! let envelope = seal(key, b"plaintext message", b"associated data").unwrap();
+//! assert_eq!(&envelope[..4], b"ASCN");
+//! assert_eq!(Header::parse(&envelope).unwrap().key_id, None);
+//!
+//! let plaintext = open(key, &envelope, b"associated data").unwrap();
+//! assert_eq!(&plaintext, b"plaintext message");
+//! # }
+//! ```
+
+use core::fmt;
+
+use aead::{Error, consts::U16};
+#[cfg(all(feature = "alloc", feature = "rand_core"))]
+use aead::{Generate, rand_core::TryCryptoRng};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    AsconAead128Key, AsconAead128Nonce,
+    asconcore::{AsconIncrementalCore, InternalKey16, Parameters128},
+};
+
+/// Magic value at the start of every envelope
+pub const MAGIC: &[u8; 4] = b"ASCN";
+/// Current format version
+pub const VERSION: u8 = 1;
+/// Maximal size of key IDs
+pub const MAX_KEY_ID_SIZE: usize = 255;
+
+/// Size of the fixed part of the header
+const FIXED_HEADER_SIZE: usize = MAGIC.len() + 3 + 16;
+
+/// Errors of envelope processing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnvelopeError {
+    /// The envelope does not start with the magic value
+    InvalidMagic,
+    /// The format version is not supported
+    UnsupportedVersion(u8),
+    /// The algorithm identifier is not supported
+    UnsupportedAlgorithm(u8),
+    /// The envelope is too short
+    Truncated,
+    /// The key ID is longer than [`MAX_KEY_ID_SIZE`]
+    KeyIdTooLong,
+    /// The output buffer is too small
+    BufferTooSmall,
+    /// Encryption or decryption failed
+    Aead(Error),
+}
+
+impl fmt::Display for EnvelopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => f.write_str("invalid magic value"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported version {version}"),
+            Self::UnsupportedAlgorithm(algorithm) => {
+                write!(f, "unsupported algorithm {algorithm:#04x}")
+            }
+            Self::Truncated => f.write_str("truncated envelope"),
+            Self::KeyIdTooLong => f.write_str("key ID too long"),
+            Self::BufferTooSmall => f.write_str("buffer too small"),
+            Self::Aead(err) => err.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for EnvelopeError {}
+
+impl From<Error> for EnvelopeError {
+    fn from(err: Error) -> Self {
+        Self::Aead(err)
+    }
+}
+
+/// Algorithms supported by envelopes
+///
+/// The identifier of Ascon-AEAD128 with a tag of `n` bytes is `0x10 | (n - 1)`, i.e., `0x1f` for
+/// the full tag and `0x13` for the shortest tag of 4 bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// Ascon-AEAD128 with tags truncated to the given number of bytes (4 to 16)
+    AsconAead128 {
+        /// Size of the tag in bytes
+        tag_size: u8,
+    },
+}
+
+impl Algorithm {
+    /// Ascon-AEAD128 with the full tag of 16 bytes
+    pub const ASCON_AEAD128: Self = Self::AsconAead128 { tag_size: 16 };
+
+    /// Return the algorithm with the given identifier.
+    pub fn from_id(id: u8) -> Result<Self, EnvelopeError> {
+        match (id >> 4, (id & 0xf) + 1) {
+            (1, tag_size @ 4..=16) => Ok(Self::AsconAead128 { tag_size }),
+            _ => Err(EnvelopeError::UnsupportedAlgorithm(id)),
+        }
+    }
+
+    /// Return the identifier of the algorithm.
+    ///
+    /// Fails if the parameters of the algorithm are invalid.
+    pub fn id(&self) -> Result<u8, EnvelopeError> {
+        match *self {
+            Self::AsconAead128 { tag_size } if (4..=16).contains(&tag_size) => {
+                Ok(0x10 | (tag_size - 1))
+            }
+            Self::AsconAead128 { tag_size } => Err(EnvelopeError::UnsupportedAlgorithm(
+                0x10 | (tag_size.wrapping_sub(1) & 0xf),
+            )),
+        }
+    }
+
+    /// Return the size of the tag in bytes.
+    pub fn tag_size(&self) -> usize {
+        match *self {
+            Self::AsconAead128 { tag_size } => tag_size as usize,
+        }
+    }
+}
+
+impl Default for Algorithm {
+    fn default() -> Self {
+        Self::ASCON_AEAD128
+    }
+}
+
+/// Header of an envelope
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header<'a> {
+    /// Algorithm used to encrypt the message
+    pub algorithm: Algorithm,
+    /// Identifier of the key
+    ///
+    /// An empty key ID is encoded like a missing key ID and is thus parsed as `None`.
+    pub key_id: Option<&'a [u8]>,
+    /// Nonce used to encrypt the message
+    pub nonce: AsconAead128Nonce,
+}
+
+impl<'a> Header<'a> {
+    /// Create a new header for Ascon-AEAD128 without key ID.
+    pub fn new(nonce: &AsconAead128Nonce) -> Self {
+        Self {
+            algorithm: Algorithm::default(),
+            key_id: None,
+            nonce: *nonce,
+        }
+    }
+
+    /// Parse the header at the start of an envelope.
+    ///
+    /// This allows looking up the key based on the key ID before opening the envelope.
+    pub fn parse(envelope: &'a [u8]) -> Result<Self, EnvelopeError> {
+        Self::parse_with_size(envelope).map(|(header, _)| header)
+    }
+
+    /// Parse the header at the start of an envelope and return its size.
+    fn parse_with_size(envelope: &'a [u8]) -> Result<(Self, usize), EnvelopeError> {
+        if envelope.len() < MAGIC.len() {
+            return Err(EnvelopeError::Truncated);
+        }
+        if &envelope[..MAGIC.len()] != MAGIC {
+            return Err(EnvelopeError::InvalidMagic);
+        }
+        if envelope.len() < FIXED_HEADER_SIZE {
+            return Err(EnvelopeError::Truncated);
+        }
+        let version = envelope[MAGIC.len()];
+        let algorithm = envelope[MAGIC.len() + 1];
+        let key_id_len = envelope[MAGIC.len() + 2];
+        if version != VERSION {
+            return Err(EnvelopeError::UnsupportedVersion(version));
+        }
+        let algorithm = Algorithm::from_id(algorithm)?;
+
+        let size = FIXED_HEADER_SIZE + key_id_len as usize;
+        if envelope.len() < size + algorithm.tag_size() {
+            return Err(EnvelopeError::Truncated);
+        }
+        let key_id = &envelope[MAGIC.len() + 3..MAGIC.len() + 3 + key_id_len as usize];
+        let nonce = AsconAead128Nonce::try_from(&envelope[size - 16..size]).unwrap();
+        Ok((
+            Self {
+                algorithm,
+                key_id: (!key_id.is_empty()).then_some(key_id),
+                nonce,
+            },
+            size,
+        ))
+    }
+
+    /// Return the size of the encoded header.
+    pub fn encoded_len(&self) -> usize {
+        FIXED_HEADER_SIZE + self.key_id.map_or(0, <[u8]>::len)
+    }
+
+    /// Return the size of an envelope with this header for a plaintext of the given size.
+    ///
+    /// Saturates at `usize::MAX` if the size is not representable.
+    pub fn envelope_len(&self, plaintext_len: usize) -> usize {
+        self.encoded_len()
+            .saturating_add(plaintext_len)
+            .saturating_add(self.algorithm.tag_size())
+    }
+
+    /// Check that the header can be encoded and return the algorithm identifier.
+    fn validate(&self) -> Result<u8, EnvelopeError> {
+        if self.key_id.map_or(0, <[u8]>::len) > MAX_KEY_ID_SIZE {
+            return Err(EnvelopeError::KeyIdTooLong);
+        }
+        self.algorithm.id()
+    }
+
+    /// Encode the validated header with the given algorithm identifier into the given buffer.
+    fn encode(&self, algorithm_id: u8, buffer: &mut [u8]) {
+        let key_id = self.key_id.unwrap_or_default();
+        buffer[..MAGIC.len()].copy_from_slice(MAGIC);
+        buffer[MAGIC.len()] = VERSION;
+        buffer[MAGIC.len() + 1] = algorithm_id;
+        buffer[MAGIC.len() + 2] = key_id.len() as u8;
+        buffer[MAGIC.len() + 3..MAGIC.len() + 3 + key_id.len()].copy_from_slice(key_id);
+        buffer[MAGIC.len() + 3 + key_id.len()..self.encoded_len()].copy_from_slice(&self.nonce);
+    }
+}
+
+/// Seal a message into an envelope in-place.
+///
+/// The plaintext is read from the first `plaintext_len` bytes of `buffer`. The envelope is written
+/// to `buffer`, which needs to hold at least [`Header::envelope_len`] bytes. Returns the size of
+/// the envelope. On error, `buffer` is left unchanged.
+pub fn seal_in_place(
+    key: &AsconAead128Key,
+    header: &Header<'_>,
+    associated_data: &[u8],
+    buffer: &mut [u8],
+    plaintext_len: usize,
+) -> Result<usize, EnvelopeError> {
+    let algorithm_id = header.validate()?;
+    let header_len = header.encoded_len();
+    let envelope_len = header.envelope_len(plaintext_len);
+    if envelope_len > buffer.len() {
+        return Err(EnvelopeError::BufferTooSmall);
+    }
+
+    buffer.copy_within(..plaintext_len, header_len);
+    header.encode(algorithm_id, buffer);
+    let (header_bytes, buffer) = buffer.split_at_mut(header_len);
+    let (message, tag) = buffer.split_at_mut(plaintext_len);
+
+    let mut core =
+        AsconIncrementalCore::<Parameters128<U16>>::new(&InternalKey16::from(key), &header.nonce);
+    core.process_associated_data(header_bytes)?;
+    core.process_associated_data(associated_data)?;
+    core.encrypt_inout(message.into());
+    let tag_size = header.algorithm.tag_size();
+    tag[..tag_size].copy_from_slice(&core.finalize()[..tag_size]);
+    Ok(envelope_len)
+}
+
+/// Open an envelope in-place.
+///
+/// Returns the header and the plaintext, which is decrypted in-place within `envelope`. If the
+/// tag is invalid, the ciphertext is cleared.
+pub fn open_in_place<'a>(
+    key: &AsconAead128Key,
+    envelope: &'a mut [u8],
+    associated_data: &[u8],
+) -> Result<(Header<'a>, &'a mut [u8]), EnvelopeError> {
+    let (header, header_len) = Header::parse_with_size(envelope)?;
+    let algorithm = header.algorithm;
+    let nonce = header.nonce;
+    let key_id_len = header.key_id.map_or(0, <[u8]>::len);
+
+    let (header_bytes, buffer) = envelope.split_at_mut(header_len);
+    let header_bytes: &'a [u8] = header_bytes;
+    let (message, tag) = buffer.split_at_mut(buffer.len() - algorithm.tag_size());
+
+    let mut core =
+        AsconIncrementalCore::<Parameters128<U16>>::new(&InternalKey16::from(key), &nonce);
+    core.process_associated_data(header_bytes)?;
+    core.process_associated_data(associated_data)?;
+    core.decrypt_inout(message.into());
+    if let Err(err) = core.verify_truncated(tag) {
+        message.fill(0);
+        return Err(err.into());
+    }
+
+    let key_id = &header_bytes[MAGIC.len() + 3..MAGIC.len() + 3 + key_id_len];
+    Ok((
+        Header {
+            algorithm,
+            key_id: (!key_id.is_empty()).then_some(key_id),
+            nonce,
+        },
+        message,
+    ))
+}
+
+/// Seal a message into an envelope with the given header.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub fn seal_with(
+    key: &AsconAead128Key,
+    header: &Header<'_>,
+    plaintext: &[u8],
+    associated_data: &[u8],
+) -> Result<Vec<u8>, EnvelopeError> {
+    header.validate()?;
+    let mut envelope = alloc::vec![0u8; header.envelope_len(plaintext.len())];
+    envelope[..plaintext.len()].copy_from_slice(plaintext);
+    seal_in_place(key, header, associated_data, &mut envelope, plaintext.len())?;
+    Ok(envelope)
+}
+
+/// Seal a message into an envelope with a nonce sampled from `rng` and the full tag.
+///
+/// Requires the `alloc` and `rand_core` features.
+#[cfg(all(feature = "alloc", feature = "rand_core"))]
+pub fn seal_with_rng<R: TryCryptoRng + ?Sized>(
+    key: &AsconAead128Key,
+    rng: &mut R,
+    plaintext: &[u8],
+    associated_data: &[u8],
+) -> Result<Vec<u8>, EnvelopeError> {
+    let nonce =
+        AsconAead128Nonce::try_generate_from_rng(rng).map_err(|_| EnvelopeError::Aead(Error))?;
+    seal_with(key, &Header::new(&nonce), plaintext, associated_data)
+}
+
+/// Seal a message into an envelope with a random nonce from the operating system and the full
+/// tag.
+///
+/// Requires the `alloc` and `getrandom` features. See [`seal_with_rng`] to provide the random
+/// number generator.
+#[cfg(all(feature = "alloc", feature = "getrandom"))]
+pub fn seal(
+    key: &AsconAead128Key,
+    plaintext: &[u8],
+    associated_data: &[u8],
+) -> Result<Vec<u8>, EnvelopeError> {
+    seal_with_rng(
+        key,
+        &mut aead::common::getrandom::SysRng,
+        plaintext,
+        associated_data,
+    )
+}
+
+/// Open an envelope and return the plaintext.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub fn open(
+    key: &AsconAead128Key,
+    envelope: &[u8],
+    associated_data: &[u8],
+) -> Result<Vec<u8>, EnvelopeError> {
+    let mut buffer = envelope.to_vec();
+    let (header, plaintext) = open_in_place(key, &mut buffer, associated_data)?;
+    let (header_len, plaintext_len) = (header.encoded_len(), plaintext.len());
+    buffer.truncate(header_len + plaintext_len);
+    buffer.drain(..header_len);
+    Ok(buffer)
+}
@@ -0,0 +1,144 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Incremental encryption and decryption
+
+use aead::{Error, consts::U16, inout::InOutBuf};
+
+use crate::{
+    AsconAead128, AsconAead128Key, AsconAead128Nonce, AsconAead128Tag,
+    asconcore::{AsconIncrementalCore, InternalKey16, Parameters128},
+};
+
+/// Incremental encryption with Ascon-AEAD128
+///
+/// Associated data and plaintext can be provided in chunks of arbitrary sizes. All associated
+/// data has to be processed before the first chunk of plaintext. The result is the same as
+/// encrypting the concatenated chunks with [`AsconAead128`].
+///
+/// ```
+/// use ascon_aead::{AsconAead128, AsconAead128Encryptor, AsconAead128Key, AsconAead128Nonce};
+/// use ascon_aead::aead::{AeadInOut, KeyInit};
+///
+/// let key = AsconAead128Key::from_slice(b"very secret key.");
+/// let nonce = AsconAead128Nonce::from_slice(b"unique nonce 012");
+///
+/// let mut encryptor = AsconAead128Encryptor::new(key, nonce);
+/// encryptor.update_associated_data(b"associated ").unwrap();
+/// encryptor.update_associated_data(b"data").unwrap();
+/// let mut buffer = *b"plaintext message";
+/// encryptor.encrypt_in_place(&mut buffer[..5]);
+/// encryptor.encrypt_in_place(&mut buffer[5..]);
+/// let tag = encryptor.finalize();
+///
+/// let mut expected = *b"plaintext message";
+/// let expected_tag = AsconAead128::new(key)
+///     .encrypt_inout_detached(nonce, b"associated data", expected.as_mut_slice().into())
+///     .unwrap();
+/// assert_eq!(buffer, expected);
+/// assert_eq!(tag, expected_tag);
+/// ```
+pub struct AsconAead128Encryptor(AsconIncrementalCore<Parameters128<U16>>);
+
+impl AsconAead128Encryptor {
+    /// Start encryption with the given key and nonce.
+    pub fn new(key: &AsconAead128Key, nonce: &AsconAead128Nonce) -> Self {
+        Self(AsconIncrementalCore::new(&InternalKey16::from(key), nonce))
+    }
+
+    /// Start encryption with the key of an existing cipher instance.
+    pub fn from_cipher(cipher: &AsconAead128, nonce: &AsconAead128Nonce) -> Self {
+        Self(AsconIncrementalCore::new(&cipher.0.key, nonce))
+    }
+
+    /// Process a chunk of associated data.
+    ///
+    /// Fails if called after processing plaintext.
+    pub fn update_associated_data(&mut self, associated_data: &[u8]) -> Result<(), Error> {
+        self.0.process_associated_data(associated_data)
+    }
+
+    /// Encrypt a chunk of plaintext.
+    pub fn encrypt_inout(&mut self, buffer: InOutBuf<'_, '_, u8>) {
+        self.0.encrypt_inout(buffer)
+    }
+
+    /// Encrypt a chunk of plaintext in-place.
+    pub fn encrypt_in_place(&mut self, buffer: &mut [u8]) {
+        self.0.encrypt_inout(buffer.into())
+    }
+
+    /// Finish encryption and return the tag.
+    pub fn finalize(self) -> AsconAead128Tag {
+        self.0.finalize()
+    }
+}
+
+/// Incremental decryption with Ascon-AEAD128
+///
+/// Associated data and ciphertext can be provided in chunks of arbitrary sizes. All associated
+/// data has to be processed before the first chunk of ciphertext.
+///
+/// **Warning**: The decryption methods release plaintext before the tag was verified. The
+/// plaintext must not be used for anything until [`AsconAead128Decryptor::verify`] succeeded. If
+/// verification fails, the plaintext has to be discarded.
+///
+/// ```
+/// use ascon_aead::{AsconAead128, AsconAead128Decryptor, AsconAead128Key, AsconAead128Nonce};
+/// use ascon_aead::aead::{AeadInOut, KeyInit};
+///
+/// let key = AsconAead128Key::from_slice(b"very secret key.");
+/// let nonce = AsconAead128Nonce::from_slice(b"unique nonce 012");
+///
+/// let mut buffer = *b"plaintext message";
+/// let tag = AsconAead128::new(key)
+///     .encrypt_inout_detached(nonce, b"", buffer.as_mut_slice().into())
+///     .unwrap();
+///
+/// let mut decryptor = AsconAead128Decryptor::new(key, nonce);
+/// decryptor.decrypt_in_place_unverified(&mut buffer[..7]);
+/// decryptor.decrypt_in_place_unverified(&mut buffer[7..]);
+/// assert!(decryptor.verify(&tag).is_ok());
+/// assert_eq!(&buffer, b"plaintext message");
+/// ```
+pub struct AsconAead128Decryptor(AsconIncrementalCore<Parameters128<U16>>);
+
+impl AsconAead128Decryptor {
+    /// Start decryption with the given key and nonce.
+    pub fn new(key: &AsconAead128Key, nonce: &AsconAead128Nonce) -> Self {
+        Self(AsconIncrementalCore::new(&InternalKey16::from(key), nonce))
+    }
+
+    /// Start decryption with the key of an existing cipher instance.
+    pub fn from_cipher(cipher: &AsconAead128, nonce: &AsconAead128Nonce) -> Self {
+        Self(AsconIncrementalCore::new(&cipher.0.key, nonce))
+    }
+
+    /// Process a chunk of associated data.
+    ///
+    /// Fails if called after processing ciphertext.
+    pub fn update_associated_data(&mut self, associated_data: &[u8]) -> Result<(), Error> {
+        self.0.process_associated_data(associated_data)
+    }
+
+    /// Decrypt a chunk of ciphertext.
+    ///
+    /// **Warning**: The returned plaintext is not authenticated until
+    /// [`AsconAead128Decryptor::verify`] succeeded.
+    pub fn decrypt_inout_unverified(&mut self, buffer: InOutBuf<'_, '_, u8>) {
+        self.0.decrypt_inout(buffer)
+    }
+
+    /// Decrypt a chunk of ciphertext in-place.
+    ///
+    /// **Warning**: The returned plaintext is not authenticated until
+    /// [`AsconAead128Decryptor::verify`] succeeded.
+    pub fn decrypt_in_place_unverified(&mut self, buffer: &mut [u8]) {
+        self.0.decrypt_inout(buffer.into())
+    }
+
+    /// Finish decryption and verify the tag.
+    pub fn verify(self, tag: &AsconAead128Tag) -> Result<(), Error> {
+        self.0.verify(tag)
+    }
+}
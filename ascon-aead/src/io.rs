@@ -0,0 +1,412 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Chunked encryption of byte streams with [`std::io`]
+//!
+//! [`EncryptingWriter`] encrypts all data written to it into an underlying [`Write`] sink and
+//! [`DecryptingReader`] decrypts data from an underlying [`Read`] source. Both only buffer a single
+//! chunk. The stream consists of a header followed by chunks encrypted with the [STREAM
+//! construction](crate::stream):
+//!
+//! ```text
+//! stream = version (1 byte) || chunk size (4 bytes) || nonce prefix (11 bytes) || chunks
+//! ```
+//!
+//! Every chunk except the last one holds exactly chunk size bytes of plaintext followed by the
+//! tag. The last chunk holds at most chunk size bytes of plaintext and may be empty. The header is
+//! authenticated as associated data of every chunk. Since STREAM binds the position of each chunk
+//! and marks the last chunk, reordered, duplicated, dropped or truncated chunks are detected.
+//!
+//! [`DecryptingReader`] only returns plaintext of chunks whose tag has been verified. Note that
+//! the plaintext of earlier chunks may have been returned before an error in a later chunk is
+//! detected. A stream is only complete once the reader returned end-of-file.
+//!
+//! With the `tokio` feature, [`AsyncEncryptWriter`] and [`AsyncDecryptReader`] provide the same
+//! format for Tokio's `AsyncWrite` and `AsyncRead`.
+//!
+//! ```
+//! use std::io::{Read, Write};
+//!
+//! use ascon_aead::AsconAead128Key;
+//! use ascon_aead::io::{DecryptingReader, EncryptingWriter};
+//! use ascon_aead::stream::StreamNoncePrefix;
+//!
+//! let key = AsconAead128Key::from_slice(b"very secret key.");
+//! // 88 bits; unique per stream
+//! let nonce_prefix = StreamNoncePrefix::from_slice(b"unique 0123");
+//!
+//! let mut writer = EncryptingWriter::new(Vec::new(), key, nonce_prefix);
+//! writer.write_all(b"plaintext message").unwrap();
+//! let ciphertext = writer.finish().unwrap();
+//!
+//! let mut reader = DecryptingReader::new(ciphertext.as_slice(), key);
+//! let mut plaintext = Vec::new();
+//! reader.read_to_end(&mut plaintext).unwrap();
+//! assert_eq!(plaintext, b"plaintext message");
+//! ```
+
+use std::io::{self, Read, Write};
+use std::vec::Vec;
+
+use aead::KeyInit;
+
+use crate::{
+    AsconAead128, AsconAead128Key,
+    stream::{StreamDecryptor, StreamEncryptor, StreamNoncePrefix},
+};
+
+#[cfg(feature = "tokio")]
+mod async_io;
+
+#[cfg(feature = "tokio")]
+pub use async_io::{AsyncDecryptReader, AsyncEncryptWriter};
+
+/// Current format version
+const VERSION: u8 = 1;
+/// Size of the header in bytes
+pub const HEADER_SIZE: usize = 16;
+/// Size of the tag of each chunk in bytes
+const TAG_SIZE: usize = 16;
+/// Default chunk size in bytes
+pub const DEFAULT_CHUNK_SIZE: usize = 1 << 16;
+/// Maximal chunk size in bytes
+///
+/// This bounds the memory required by [`DecryptingReader`] for untrusted streams.
+pub const MAX_CHUNK_SIZE: usize = 1 << 24;
+
+/// Create an error for malformed or unauthentic streams.
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Header of a chunked stream
+#[derive(Clone, Copy)]
+struct Header {
+    chunk_size: usize,
+    nonce_prefix: StreamNoncePrefix,
+}
+
+impl Header {
+    fn new(chunk_size: usize, nonce_prefix: &StreamNoncePrefix) -> io::Result<Self> {
+        if chunk_size == 0 || chunk_size > MAX_CHUNK_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid chunk size",
+            ));
+        }
+        Ok(Self {
+            chunk_size,
+            nonce_prefix: *nonce_prefix,
+        })
+    }
+
+    fn encode(&self) -> [u8; HEADER_SIZE] {
+        let mut header = [0u8; HEADER_SIZE];
+        header[0] = VERSION;
+        header[1..5].copy_from_slice(&(self.chunk_size as u32).to_be_bytes());
+        header[5..].copy_from_slice(&self.nonce_prefix);
+        header
+    }
+
+    fn decode(header: &[u8; HEADER_SIZE]) -> io::Result<Self> {
+        if header[0] != VERSION {
+            return Err(invalid_data("unsupported version"));
+        }
+        let chunk_size = u32::from_be_bytes(header[1..5].try_into().unwrap()) as usize;
+        let nonce_prefix = StreamNoncePrefix::try_from(&header[5..]).unwrap();
+        Self::new(chunk_size, &nonce_prefix).map_err(|_| invalid_data("invalid chunk size"))
+    }
+}
+
+/// Encryption of chunks independent of the I/O interface
+struct ChunkEncoder {
+    encryptor: StreamEncryptor<AsconAead128>,
+    header: [u8; HEADER_SIZE],
+}
+
+impl ChunkEncoder {
+    fn new(aead: AsconAead128, header: &Header) -> Self {
+        Self {
+            encryptor: StreamEncryptor::from_aead(aead, &header.nonce_prefix),
+            header: header.encode(),
+        }
+    }
+
+    /// Encrypt the plaintext in `buffer` in-place as the next chunk.
+    fn seal_next(&mut self, buffer: &mut Vec<u8>) -> io::Result<()> {
+        self.encryptor
+            .encrypt_next_in_place(&self.header, buffer)
+            .map_err(|_| io::Error::other("too many chunks"))
+    }
+
+    /// Encrypt the plaintext in `buffer` in-place as the last chunk.
+    fn seal_last(self, buffer: &mut Vec<u8>) -> io::Result<()> {
+        self.encryptor
+            .encrypt_last_in_place(&self.header, buffer)
+            .map_err(|_| io::Error::other("encryption failed"))
+    }
+}
+
+/// Decryption of chunks independent of the I/O interface
+struct ChunkDecoder {
+    decryptor: StreamDecryptor<AsconAead128>,
+    header: [u8; HEADER_SIZE],
+    chunk_size: usize,
+}
+
+impl ChunkDecoder {
+    fn new(aead: AsconAead128, header: &[u8; HEADER_SIZE]) -> io::Result<Self> {
+        let decoded = Header::decode(header)?;
+        Ok(Self {
+            decryptor: StreamDecryptor::from_aead(aead, &decoded.nonce_prefix),
+            header: *header,
+            chunk_size: decoded.chunk_size,
+        })
+    }
+
+    /// Size of an encrypted chunk other than the last one
+    fn encrypted_chunk_size(&self) -> usize {
+        self.chunk_size + TAG_SIZE
+    }
+
+    /// Decrypt the chunk in `buffer` in-place as the next chunk.
+    fn open_next(&mut self, buffer: &mut Vec<u8>) -> io::Result<()> {
+        self.decryptor
+            .decrypt_next_in_place(&self.header, buffer)
+            .map_err(|_| invalid_data("invalid chunk"))
+    }
+
+    /// Decrypt the chunk in `buffer` in-place as the last chunk.
+    fn open_last(self, buffer: &mut Vec<u8>) -> io::Result<()> {
+        if buffer.len() < TAG_SIZE {
+            return Err(invalid_data("truncated stream"));
+        }
+        self.decryptor
+            .decrypt_last_in_place(&self.header, buffer)
+            .map_err(|_| invalid_data("invalid chunk"))
+    }
+}
+
+/// Writer encrypting all data into an underlying writer
+///
+/// Data is buffered until a full chunk is available. The stream has to be completed by calling
+/// [`EncryptingWriter::finish`], otherwise the stream is truncated and will fail to decrypt.
+/// [`Write::flush`] only flushes the underlying writer and does not write partial chunks. After
+/// an error, the writer cannot be used anymore.
+pub struct EncryptingWriter<W: Write> {
+    inner: W,
+    // `None` if an error occurred
+    encoder: Option<ChunkEncoder>,
+    header: Option<[u8; HEADER_SIZE]>,
+    chunk_size: usize,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> EncryptingWriter<W> {
+    /// Create a new writer with the default chunk size.
+    pub fn new(inner: W, key: &AsconAead128Key, nonce_prefix: &StreamNoncePrefix) -> Self {
+        Self::with_chunk_size(inner, key, nonce_prefix, DEFAULT_CHUNK_SIZE).unwrap()
+    }
+
+    /// Create a new writer with the given chunk size.
+    ///
+    /// Fails if the chunk size is 0 or exceeds [`MAX_CHUNK_SIZE`].
+    pub fn with_chunk_size(
+        inner: W,
+        key: &AsconAead128Key,
+        nonce_prefix: &StreamNoncePrefix,
+        chunk_size: usize,
+    ) -> io::Result<Self> {
+        let header = Header::new(chunk_size, nonce_prefix)?;
+        Ok(Self {
+            inner,
+            encoder: Some(ChunkEncoder::new(AsconAead128::new(key), &header)),
+            header: Some(header.encode()),
+            chunk_size,
+            buffer: Vec::with_capacity(chunk_size + TAG_SIZE),
+        })
+    }
+
+    /// Return a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Write the header if it has not been written yet.
+    fn write_header(&mut self) -> io::Result<()> {
+        if let Some(header) = self.header {
+            self.inner.write_all(&header)?;
+            self.header = None;
+        }
+        Ok(())
+    }
+
+    /// Encrypt the last chunk, flush the underlying writer and return it.
+    pub fn finish(mut self) -> io::Result<W> {
+        let encoder = self
+            .encoder
+            .take()
+            .ok_or_else(|| io::Error::other("writer failed"))?;
+        self.write_header()?;
+        encoder.seal_last(&mut self.buffer)?;
+        self.inner.write_all(&self.buffer)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for EncryptingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let Some(mut encoder) = self.encoder.take() else {
+            return Err(io::Error::other("writer failed"));
+        };
+        if buf.is_empty() {
+            self.encoder = Some(encoder);
+            return Ok(0);
+        }
+
+        self.write_header()?;
+        // a full chunk is only encrypted once more data follows, since it could be the last one
+        if self.buffer.len() == self.chunk_size {
+            encoder.seal_next(&mut self.buffer)?;
+            self.inner.write_all(&self.buffer)?;
+            self.buffer.clear();
+        }
+        let len = buf.len().min(self.chunk_size - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..len]);
+        self.encoder = Some(encoder);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// State of a [`DecryptingReader`]
+enum ReaderState {
+    /// The header has not been read yet
+    Header,
+    /// The header has been read and more chunks follow
+    Chunks(ChunkDecoder),
+    /// The last chunk has been decrypted
+    Done,
+    /// An error occurred
+    Failed,
+}
+
+/// Reader decrypting data from an underlying reader
+///
+/// Plaintext is only returned after the tag of its chunk has been verified. End-of-file is only
+/// reported after the last chunk has been successfully decrypted. After an error, including
+/// errors of the underlying reader, the reader cannot be used anymore.
+pub struct DecryptingReader<R: Read> {
+    inner: R,
+    // taken once the header has been read
+    aead: Option<AsconAead128>,
+    state: ReaderState,
+    buffer: Vec<u8>,
+    position: usize,
+    // first byte of the next chunk
+    lookahead: Option<u8>,
+}
+
+impl<R: Read> DecryptingReader<R> {
+    /// Create a new reader.
+    pub fn new(inner: R, key: &AsconAead128Key) -> Self {
+        Self {
+            inner,
+            aead: Some(AsconAead128::new(key)),
+            state: ReaderState::Header,
+            buffer: Vec::new(),
+            position: 0,
+            lookahead: None,
+        }
+    }
+
+    /// Return a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Return the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read and decrypt the next chunk into the buffer.
+    ///
+    /// On error, the buffer is cleared so that no unauthenticated data is ever returned.
+    fn fill_buffer(&mut self) -> io::Result<()> {
+        let result = self.read_chunk();
+        if result.is_err() {
+            self.buffer.clear();
+            self.position = 0;
+        }
+        result
+    }
+
+    fn read_chunk(&mut self) -> io::Result<()> {
+        let mut decoder = match core::mem::replace(&mut self.state, ReaderState::Failed) {
+            ReaderState::Header => {
+                let mut header = [0u8; HEADER_SIZE];
+                self.inner.read_exact(&mut header).map_err(|err| {
+                    if err.kind() == io::ErrorKind::UnexpectedEof {
+                        invalid_data("truncated stream")
+                    } else {
+                        err
+                    }
+                })?;
+                ChunkDecoder::new(self.aead.take().unwrap(), &header)?
+            }
+            ReaderState::Chunks(decoder) => decoder,
+            ReaderState::Done => {
+                self.state = ReaderState::Done;
+                return Ok(());
+            }
+            ReaderState::Failed => return Err(io::Error::other("reader failed")),
+        };
+
+        // read one byte more than a full chunk to determine whether this is the last chunk
+        let limit = decoder.encrypted_chunk_size() + 1;
+        self.buffer.clear();
+        self.buffer.reserve(limit);
+        self.buffer.extend(self.lookahead.take());
+        self.position = 0;
+        (&mut self.inner)
+            .take((limit - self.buffer.len()) as u64)
+            .read_to_end(&mut self.buffer)?;
+
+        if self.buffer.len() == limit {
+            self.lookahead = self.buffer.pop();
+            decoder.open_next(&mut self.buffer)?;
+            self.state = ReaderState::Chunks(decoder);
+        } else {
+            decoder.open_last(&mut self.buffer)?;
+            self.state = ReaderState::Done;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for DecryptingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if matches!(self.state, ReaderState::Failed) {
+            return Err(io::Error::other("reader failed"));
+        }
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        while self.position == self.buffer.len() {
+            if matches!(self.state, ReaderState::Done) {
+                return Ok(0);
+            }
+            self.fill_buffer()?;
+        }
+
+        let len = buf.len().min(self.buffer.len() - self.position);
+        buf[..len].copy_from_slice(&self.buffer[self.position..self.position + len]);
+        self.position += len;
+        Ok(len)
+    }
+}
@@ -0,0 +1,379 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Chunked encryption of byte streams with Tokio
+
+use core::{
+    mem,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+use std::{io, vec::Vec};
+
+use aead::KeyInit;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use super::{
+    ChunkDecoder, ChunkEncoder, DEFAULT_CHUNK_SIZE, HEADER_SIZE, Header, TAG_SIZE, invalid_data,
+};
+use crate::{AsconAead128, AsconAead128Key, stream::StreamNoncePrefix};
+
+/// Asynchronous writer encrypting all data into an underlying writer
+///
+/// This is the asynchronous counterpart of [`EncryptingWriter`](super::EncryptingWriter) producing
+/// the same format. The stream is completed by shutting down the writer (see
+/// [`AsyncWrite::poll_shutdown`]), otherwise the stream is truncated and will fail to decrypt.
+/// Flushing writes all encrypted chunks, but does not write partial chunks.
+///
+/// All state is kept in the writer, so dropping a pending write or flush does not lose data that
+/// was reported as written. After an error, the writer cannot be used anymore.
+pub struct AsyncEncryptWriter<W> {
+    inner: W,
+    // `None` once the last chunk has been encrypted or if an error occurred
+    encoder: Option<ChunkEncoder>,
+    failed: bool,
+    chunk_size: usize,
+    // plaintext of the current chunk
+    buffer: Vec<u8>,
+    // encrypted data that still has to be written to the underlying writer
+    pending: Vec<u8>,
+    position: usize,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncEncryptWriter<W> {
+    /// Create a new writer with the default chunk size.
+    pub fn new(inner: W, key: &AsconAead128Key, nonce_prefix: &StreamNoncePrefix) -> Self {
+        Self::with_chunk_size(inner, key, nonce_prefix, DEFAULT_CHUNK_SIZE).unwrap()
+    }
+
+    /// Create a new writer with the given chunk size.
+    ///
+    /// Fails if the chunk size is 0 or exceeds [`MAX_CHUNK_SIZE`](super::MAX_CHUNK_SIZE).
+    pub fn with_chunk_size(
+        inner: W,
+        key: &AsconAead128Key,
+        nonce_prefix: &StreamNoncePrefix,
+        chunk_size: usize,
+    ) -> io::Result<Self> {
+        let header = Header::new(chunk_size, nonce_prefix)?;
+        let mut pending = Vec::with_capacity(chunk_size + TAG_SIZE);
+        pending.extend_from_slice(&header.encode());
+        Ok(Self {
+            inner,
+            encoder: Some(ChunkEncoder::new(AsconAead128::new(key), &header)),
+            failed: false,
+            chunk_size,
+            buffer: Vec::with_capacity(chunk_size + TAG_SIZE),
+            pending,
+            position: 0,
+        })
+    }
+
+    /// Return a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Write pending encrypted data to the underlying writer.
+    fn poll_pending(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.position < self.pending.len() {
+            let len =
+                ready!(Pin::new(&mut self.inner).poll_write(cx, &self.pending[self.position..]))?;
+            if len == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.position += len;
+        }
+        self.pending.clear();
+        self.position = 0;
+        Poll::Ready(Ok(()))
+    }
+
+    /// Encrypt the current chunk and queue it for writing.
+    fn seal(&mut self, last: bool) -> io::Result<()> {
+        if last {
+            let encoder = self.encoder.take().ok_or_else(failed)?;
+            encoder.seal_last(&mut self.buffer)?;
+        } else {
+            let encoder = self.encoder.as_mut().ok_or_else(failed)?;
+            encoder.seal_next(&mut self.buffer)?;
+        }
+        mem::swap(&mut self.buffer, &mut self.pending);
+        self.buffer.clear();
+        Ok(())
+    }
+
+    /// Buffer data of the current chunk, encrypting the previous chunk if it is full.
+    fn poll_write_chunk(&mut self, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        if self.encoder.is_none() {
+            return Poll::Ready(Err(failed()));
+        }
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        ready!(self.poll_pending(cx))?;
+        // a full chunk is only encrypted once more data follows, since it could be the last one
+        if self.buffer.len() == self.chunk_size {
+            self.seal(false)?;
+            // the data is accepted once the chunk has been encrypted; writing it may be pending
+            if let Poll::Ready(Err(err)) = self.poll_pending(cx) {
+                return Poll::Ready(Err(err));
+            }
+        }
+        let len = buf.len().min(self.chunk_size - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..len]);
+        Poll::Ready(Ok(len))
+    }
+
+    /// Encrypt the last chunk, write all pending data and shut down the underlying writer.
+    fn poll_shutdown_stream(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if self.encoder.is_some() {
+            ready!(self.poll_pending(cx))?;
+            self.seal(true)?;
+        }
+        ready!(self.poll_pending(cx))?;
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+
+    /// Mark the writer as failed if `result` is an error.
+    fn check<T>(&mut self, result: Poll<io::Result<T>>) -> Poll<io::Result<T>> {
+        if let Poll::Ready(Err(_)) = result {
+            self.failed = true;
+            self.encoder = None;
+        }
+        result
+    }
+}
+
+/// Create an error for writers and readers that cannot be used anymore.
+fn failed() -> io::Error {
+    io::Error::other("stream failed or already completed")
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for AsyncEncryptWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let result = this.poll_write_chunk(cx, buf);
+        this.check(result)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.failed {
+            return Poll::Ready(Err(failed()));
+        }
+        let result = this.poll_pending(cx);
+        ready!(this.check(result))?;
+        let result = Pin::new(&mut this.inner).poll_flush(cx);
+        this.check(result)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.failed {
+            return Poll::Ready(Err(failed()));
+        }
+        let result = this.poll_shutdown_stream(cx);
+        this.check(result)
+    }
+}
+
+/// State of an [`AsyncDecryptReader`]
+enum ReaderState {
+    /// Reading the header
+    Header {
+        header: [u8; HEADER_SIZE],
+        filled: usize,
+    },
+    /// Reading a chunk into the buffer
+    Chunk {
+        decoder: ChunkDecoder,
+        filled: usize,
+    },
+    /// Returning the plaintext in the buffer; `None` if it was the last chunk
+    Plaintext {
+        decoder: Option<ChunkDecoder>,
+        position: usize,
+    },
+    /// The last chunk has been returned
+    Done,
+    /// An error occurred
+    Failed,
+}
+
+/// Asynchronous reader decrypting data from an underlying reader
+///
+/// This is the asynchronous counterpart of [`DecryptingReader`](super::DecryptingReader). Plaintext
+/// is only returned after the tag of its chunk has been verified and end-of-file is only reported
+/// after the last chunk has been successfully decrypted.
+///
+/// All state is kept in the reader, so dropping a pending read does not lose data. After an error,
+/// including errors of the underlying reader, the reader cannot be used anymore.
+pub struct AsyncDecryptReader<R> {
+    inner: R,
+    // taken once the header has been read
+    aead: Option<AsconAead128>,
+    state: ReaderState,
+    buffer: Vec<u8>,
+    // first byte of the next chunk
+    lookahead: Option<u8>,
+}
+
+impl<R: AsyncRead + Unpin> AsyncDecryptReader<R> {
+    /// Create a new reader.
+    pub fn new(inner: R, key: &AsconAead128Key) -> Self {
+        Self {
+            inner,
+            aead: Some(AsconAead128::new(key)),
+            state: ReaderState::Header {
+                header: [0u8; HEADER_SIZE],
+                filled: 0,
+            },
+            buffer: Vec::new(),
+            lookahead: None,
+        }
+    }
+
+    /// Return a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Return the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Prepare the buffer for reading the next chunk.
+    fn start_chunk(&mut self, decoder: ChunkDecoder) -> ReaderState {
+        // read one byte more than a full chunk to determine whether this is the last chunk
+        let limit = decoder.encrypted_chunk_size() + 1;
+        self.buffer.clear();
+        self.buffer.extend(self.lookahead.take());
+        let filled = self.buffer.len();
+        self.buffer.resize(limit, 0);
+        ReaderState::Chunk { decoder, filled }
+    }
+
+    /// Read from the underlying reader into `buffer`.
+    fn poll_fill(
+        inner: &mut R,
+        cx: &mut Context<'_>,
+        buffer: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut read_buf = ReadBuf::new(buffer);
+        ready!(Pin::new(inner).poll_read(cx, &mut read_buf))?;
+        Poll::Ready(Ok(read_buf.filled().len()))
+    }
+
+    /// Advance the state machine until plaintext is available, the stream ended, or the
+    /// underlying reader is pending.
+    ///
+    /// The state is left as [`ReaderState::Failed`] if an error is returned.
+    fn poll_advance(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        loop {
+            self.state = match mem::replace(&mut self.state, ReaderState::Failed) {
+                ReaderState::Header {
+                    mut header,
+                    mut filled,
+                } => {
+                    let read = Self::poll_fill(&mut self.inner, cx, &mut header[filled..]);
+                    let Poll::Ready(len) = read else {
+                        self.state = ReaderState::Header { header, filled };
+                        return Poll::Pending;
+                    };
+                    match len? {
+                        0 => return Poll::Ready(Err(invalid_data("truncated stream"))),
+                        len => filled += len,
+                    }
+                    if filled < HEADER_SIZE {
+                        ReaderState::Header { header, filled }
+                    } else {
+                        let aead = self.aead.take().unwrap();
+                        let decoder = ChunkDecoder::new(aead, &header)?;
+                        self.start_chunk(decoder)
+                    }
+                }
+                ReaderState::Chunk {
+                    mut decoder,
+                    mut filled,
+                } => {
+                    let read = Self::poll_fill(&mut self.inner, cx, &mut self.buffer[filled..]);
+                    let Poll::Ready(len) = read else {
+                        self.state = ReaderState::Chunk { decoder, filled };
+                        return Poll::Pending;
+                    };
+                    match len? {
+                        0 => {
+                            self.buffer.truncate(filled);
+                            decoder.open_last(&mut self.buffer)?;
+                            ReaderState::Plaintext {
+                                decoder: None,
+                                position: 0,
+                            }
+                        }
+                        len if filled + len == self.buffer.len() => {
+                            self.lookahead = self.buffer.pop();
+                            decoder.open_next(&mut self.buffer)?;
+                            ReaderState::Plaintext {
+                                decoder: Some(decoder),
+                                position: 0,
+                            }
+                        }
+                        len => {
+                            filled += len;
+                            ReaderState::Chunk { decoder, filled }
+                        }
+                    }
+                }
+                ReaderState::Plaintext { decoder, position } if position < self.buffer.len() => {
+                    self.state = ReaderState::Plaintext { decoder, position };
+                    return Poll::Ready(Ok(()));
+                }
+                ReaderState::Plaintext {
+                    decoder: Some(decoder),
+                    ..
+                } => self.start_chunk(decoder),
+                ReaderState::Plaintext { decoder: None, .. } | ReaderState::Done => {
+                    self.state = ReaderState::Done;
+                    return Poll::Ready(Ok(()));
+                }
+                ReaderState::Failed => return Poll::Ready(Err(failed())),
+            }
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for AsyncDecryptReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if buf.remaining() == 0 {
+            return Poll::Ready(Ok(()));
+        }
+        if let Err(err) = ready!(this.poll_advance(cx)) {
+            this.buffer.clear();
+            return Poll::Ready(Err(err));
+        }
+
+        if let ReaderState::Plaintext { position, .. } = &mut this.state {
+            let len = buf.remaining().min(this.buffer.len() - *position);
+            buf.put_slice(&this.buffer[*position..*position + len]);
+            *position += len;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
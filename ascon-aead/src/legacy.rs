@@ -0,0 +1,102 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Ascon v1.2 as submitted to the CAESAR competition and NIST's Lightweight Cryptography project
+
+use aead::{
+    AeadCore, AeadInOut, Error, Key, KeyInit, KeySizeUser, Nonce, Tag, TagPosition,
+    consts::{U16, U20},
+    inout::InOutBuf,
+};
+
+use crate::{
+    Ascon,
+    asconcore::{ParametersAscon80pq, ParametersAscon128, ParametersAscon128a},
+};
+
+macro_rules! impl_legacy_aead {
+    ($name:ident, $key_size:ty) => {
+        impl KeySizeUser for $name {
+            type KeySize = $key_size;
+        }
+
+        impl KeyInit for $name {
+            fn new(key: &Key<Self>) -> Self {
+                Self(Ascon::new(key))
+            }
+        }
+
+        impl AeadCore for $name {
+            type NonceSize = U16;
+            type TagSize = U16;
+            const TAG_POSITION: TagPosition = TagPosition::Postfix;
+        }
+
+        impl AeadInOut for $name {
+            #[inline(always)]
+            fn encrypt_inout_detached(
+                &self,
+                nonce: &Nonce<Self>,
+                associated_data: &[u8],
+                buffer: InOutBuf<'_, '_, u8>,
+            ) -> Result<Tag<Self>, Error> {
+                self.0
+                    .encrypt_inout_detached(nonce, associated_data, buffer)
+            }
+
+            #[inline(always)]
+            fn decrypt_inout_detached(
+                &self,
+                nonce: &Nonce<Self>,
+                associated_data: &[u8],
+                buffer: InOutBuf<'_, '_, u8>,
+                tag: &Tag<Self>,
+            ) -> Result<(), Error> {
+                self.0
+                    .decrypt_inout_detached(nonce, associated_data, buffer, tag)
+            }
+        }
+    };
+}
+
+/// Ascon-128 (v1.2)
+///
+/// This variant is superseded by [`AsconAead128`](crate::AsconAead128) and only provided for
+/// interoperability with existing deployments.
+pub struct Ascon128(Ascon<ParametersAscon128>);
+/// Key for Ascon-128
+pub type Ascon128Key = Key<Ascon128>;
+/// Nonce for Ascon-128
+pub type Ascon128Nonce = Nonce<Ascon128>;
+/// Tag for Ascon-128
+pub type Ascon128Tag = Tag<Ascon128>;
+
+impl_legacy_aead!(Ascon128, U16);
+
+/// Ascon-128a (v1.2)
+///
+/// This variant is superseded by [`AsconAead128`](crate::AsconAead128) and only provided for
+/// interoperability with existing deployments.
+pub struct Ascon128a(Ascon<ParametersAscon128a>);
+/// Key for Ascon-128a
+pub type Ascon128aKey = Key<Ascon128a>;
+/// Nonce for Ascon-128a
+pub type Ascon128aNonce = Nonce<Ascon128a>;
+/// Tag for Ascon-128a
+pub type Ascon128aTag = Tag<Ascon128a>;
+
+impl_legacy_aead!(Ascon128a, U16);
+
+/// Ascon-80pq (v1.2)
+///
+/// This variant uses a 160 bit key. It is only provided for interoperability with existing
+/// deployments.
+pub struct Ascon80pq(Ascon<ParametersAscon80pq>);
+/// Key for Ascon-80pq
+pub type Ascon80pqKey = Key<Ascon80pq>;
+/// Nonce for Ascon-80pq
+pub type Ascon80pqNonce = Nonce<Ascon80pq>;
+/// Tag for Ascon-80pq
+pub type Ascon80pqTag = Tag<Ascon80pq>;
+
+impl_legacy_aead!(Ascon80pq, U20);
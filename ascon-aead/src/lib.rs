@@ -70,8 +70,8 @@
 //! ## Truncated Tags
 //!
 //! Ascon-AEAD128 also supports truncated tags ranging from 32 to 128 bits.
-//! Currently, only byte lengths are supported. Support for truncated tags is
-//! available via [`TruncatedAsconAEAD128`].
+//! Tags of whole bytes are available via [`TruncatedAsconAead128`] and
+//! [`DynTagAsconAead128`], which takes the tag length as runtime value.
 //!
 //! ```
 //! use aead::consts::{U5};
@@ -93,21 +93,108 @@
 //!
 //! assert_eq!(&plaintext, b"plaintext message");
 //! ```
+//!
+//! Tags of arbitrary bit lengths are available via [`BitTruncatedAsconAead128`].
+//! The tag is stored in the smallest number of bytes that can hold all bits and
+//! the unused bits of the last byte are zero.
+//!
+//! ```
+//! use aead::consts::{U36};
+//! use ascon_aead::{BitTruncatedAsconAead128, Key, Nonce};
+//! use ascon_aead::aead::{AeadInOut, KeyInit};
+//!
+//! type TruncatedAscon = BitTruncatedAsconAead128<U36>;
+//! let key = Key::<TruncatedAscon>::from_slice(b"very secret key.");
+//! let cipher = TruncatedAscon::new(key);
+//! let nonce = Nonce::<TruncatedAscon>::from_slice(b"unique nonce 012");
+//!
+//! let mut buffer = *b"plaintext message";
+//! let tag = cipher.encrypt_inout_detached(nonce, b"", buffer.as_mut_slice().into())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//! assert_eq!(tag.len(), 5);
+//! assert_eq!(tag[4] & 0xf0, 0);
+//! ```
+//!
+//! ## Ascon v1.2
+//!
+//! The variants Ascon-128, Ascon-128a and Ascon-80pq from version 1.2 of the Ascon
+//! specification are available via the `legacy` feature as [`Ascon128`], [`Ascon128a`] and
+//! [`Ascon80pq`]. They are not compatible with Ascon-AEAD128 and should only be used to
+//! interoperate with existing deployments.
+//!
+//! ```
+//! # #[cfg(all(feature = "legacy", feature = "alloc"))] {
+//! use ascon_aead::{Ascon128a, Ascon128aKey, Ascon128aNonce};
+//! use ascon_aead::aead::{Aead, KeyInit};
+//!
+//! let key = Ascon128aKey::from_slice(b"very secret key.");
+//! let cipher = Ascon128a::new(key);
+//! let nonce = Ascon128aNonce::from_slice(b"unique nonce 012");
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref()).unwrap();
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref()).unwrap();
+//! assert_eq!(&plaintext, b"plaintext message");
+//! # }
+//! ```
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub use aead::{self, Error, Key, Nonce, Tag};
 use aead::{
     AeadCore, AeadInOut, KeyInit, KeySizeUser, TagPosition,
     array::{
         ArraySize,
-        typenum::{IsGreaterOrEqual, IsLessOrEqual},
+        typenum::{IsGreaterOrEqual, IsLessOrEqual, Unsigned},
     },
-    consts::{True, U4, U16},
+    consts::{True, U4, U16, U32, U128},
     inout::InOutBuf,
 };
 
 mod asconcore;
+pub mod batch;
+#[cfg(feature = "committing")]
+mod committing;
+mod dyn_tag;
+pub mod envelope;
+mod incremental;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "legacy")]
+mod legacy;
+mod limited;
+pub mod nonce;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(feature = "ratchet")]
+mod ratchet;
+#[cfg(feature = "siv")]
+mod siv;
+pub mod stream;
 
-use asconcore::{AsconCore, Parameters, Parameters128};
+pub use asconcore::TagBits;
+use asconcore::{
+    AsconCore, Parameters, Parameters128, Parameters128Bits, Parameters128NonceMasked,
+};
+#[cfg(feature = "committing")]
+pub use committing::{
+    CommittingAsconAead128, CommittingAsconAead128Key, CommittingAsconAead128Nonce,
+    CommittingAsconAead128Tag, KeyCommittingAead,
+};
+pub use dyn_tag::DynTagAsconAead128;
+pub use incremental::{AsconAead128Decryptor, AsconAead128Encryptor};
+#[cfg(feature = "legacy")]
+pub use legacy::{
+    Ascon80pq, Ascon80pqKey, Ascon80pqNonce, Ascon80pqTag, Ascon128, Ascon128Key, Ascon128Nonce,
+    Ascon128Tag, Ascon128a, Ascon128aKey, Ascon128aNonce, Ascon128aTag,
+};
+pub use limited::{LimitedAsconAead128, UsageCounters, UsageError, UsageLimits};
+#[cfg(feature = "ratchet")]
+pub use ratchet::{RatchetPolicy, RatchetingAsconAead128};
+#[cfg(feature = "siv")]
+pub use siv::{AsconSiv, AsconSivKey, AsconSivNonce, AsconSivTag};
 
 /// Ascon generic over some Parameters
 ///
@@ -173,6 +260,46 @@ impl<P: Parameters> AeadInOut for Ascon<P> {
     }
 }
 
+impl<P: Parameters> Ascon<P> {
+    /// Verify the tag of a ciphertext without decrypting it.
+    fn verify_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        if (ciphertext.len() as u64)
+            .checked_add(associated_data.len() as u64)
+            .is_none()
+        {
+            return Err(Error);
+        }
+
+        let mut core = AsconCore::<P>::new(&self.key, nonce);
+        core.verify(ciphertext, associated_data, tag)
+    }
+
+    /// Decrypt with verification of the tag before writing any plaintext.
+    fn decrypt_inout_detached_verify_first(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        if (buffer.len() as u64)
+            .checked_add(associated_data.len() as u64)
+            .is_none()
+        {
+            return Err(Error);
+        }
+
+        let mut core = AsconCore::<P>::new(&self.key, nonce);
+        core.decrypt_inout_verify_first(buffer, associated_data, tag)
+    }
+}
+
 /// Ascon-AEAD128
 pub struct AsconAead128(Ascon<Parameters128<U16>>);
 /// Key for Ascon-AEAD128
@@ -182,6 +309,57 @@ pub type AsconAead128Nonce = Nonce<AsconAead128>;
 /// Tag for Ascon-AEAD128
 pub type AsconAead128Tag = Tag<AsconAead128>;
 
+impl AsconAead128 {
+    /// Verify the tag of a ciphertext without decrypting it.
+    ///
+    /// The ciphertext is only absorbed to recompute the tag, which is then compared to `tag` in
+    /// constant time. No plaintext is produced.
+    ///
+    /// ```
+    /// use ascon_aead::{AsconAead128, AsconAead128Key, AsconAead128Nonce};
+    /// use ascon_aead::aead::{AeadInOut, KeyInit};
+    ///
+    /// let key = AsconAead128Key::from_slice(b"very secret key.");
+    /// let nonce = AsconAead128Nonce::from_slice(b"unique nonce 012");
+    /// let cipher = AsconAead128::new(key);
+    ///
+    /// let mut buffer = *b"plaintext message";
+    /// let tag = cipher
+    ///     .encrypt_inout_detached(nonce, b"associated data", buffer.as_mut_slice().into())
+    ///     .unwrap();
+    /// assert!(cipher.verify_detached(nonce, b"associated data", &buffer, &tag).is_ok());
+    /// assert!(cipher.verify_detached(nonce, b"other data", &buffer, &tag).is_err());
+    /// ```
+    pub fn verify_detached(
+        &self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+        tag: &AsconAead128Tag,
+    ) -> Result<(), Error> {
+        self.0
+            .verify_detached(nonce, associated_data, ciphertext, tag)
+    }
+
+    /// Verify the tag appended to a ciphertext without decrypting it.
+    ///
+    /// Fails if the input is shorter than a tag or if the tag is invalid.
+    pub fn verify(
+        &self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        ciphertext_with_tag: &[u8],
+    ) -> Result<(), Error> {
+        let ciphertext_len = ciphertext_with_tag
+            .len()
+            .checked_sub(U16::USIZE)
+            .ok_or(Error)?;
+        let (ciphertext, tag) = ciphertext_with_tag.split_at(ciphertext_len);
+        let tag = AsconAead128Tag::try_from(tag).map_err(|_| Error)?;
+        self.verify_detached(nonce, associated_data, ciphertext, &tag)
+    }
+}
+
 impl KeySizeUser for AsconAead128 {
     type KeySize = U16;
 }
@@ -223,6 +401,60 @@ impl AeadInOut for AsconAead128 {
     }
 }
 
+/// Ascon-AEAD128 with nonce masking
+///
+/// Variant of Ascon-AEAD128 with a 256 bit key as described in SP 800-232. The first half of the
+/// key is used as key of Ascon-AEAD128 and the second half masks the nonce. This improves the
+/// security in settings with many keys.
+pub struct AsconAead128NonceMasked(Ascon<Parameters128NonceMasked>);
+/// Key for Ascon-AEAD128 with nonce masking
+pub type AsconAead128NonceMaskedKey = Key<AsconAead128NonceMasked>;
+/// Nonce for Ascon-AEAD128 with nonce masking
+pub type AsconAead128NonceMaskedNonce = Nonce<AsconAead128NonceMasked>;
+/// Tag for Ascon-AEAD128 with nonce masking
+pub type AsconAead128NonceMaskedTag = Tag<AsconAead128NonceMasked>;
+
+impl KeySizeUser for AsconAead128NonceMasked {
+    type KeySize = U32;
+}
+
+impl KeyInit for AsconAead128NonceMasked {
+    fn new(key: &Key<Self>) -> Self {
+        Self(Ascon::new(key))
+    }
+}
+
+impl AeadCore for AsconAead128NonceMasked {
+    type NonceSize = U16;
+    type TagSize = U16;
+    const TAG_POSITION: TagPosition = TagPosition::Postfix;
+}
+
+impl AeadInOut for AsconAead128NonceMasked {
+    #[inline(always)]
+    fn encrypt_inout_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+    ) -> Result<Tag<Self>, Error> {
+        self.0
+            .encrypt_inout_detached(nonce, associated_data, buffer)
+    }
+
+    #[inline(always)]
+    fn decrypt_inout_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        self.0
+            .decrypt_inout_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
 /// Truncated Ascon-AEAD128
 ///
 /// Tag sizes of 4 to 16 bytes are supported.
@@ -288,3 +520,132 @@ where
             .decrypt_inout_detached(nonce, associated_data, buffer, tag)
     }
 }
+
+/// Ascon-AEAD128 with tags truncated to a number of bits
+///
+/// Tag sizes of 32 to 128 bits are supported. The tag is stored in the smallest number of bytes
+/// that can hold the given number of bits with the unused bits of the last byte set to zero. Only
+/// the significant bits are compared on decryption.
+pub struct BitTruncatedAsconAead128<Bits: TagBits = U128>(Ascon<Parameters128Bits<Bits>>);
+/// Key for bit-truncated Ascon-AEAD128
+pub type BitTruncatedAsconAead128Key<Bits = U128> = Key<BitTruncatedAsconAead128<Bits>>;
+/// Nonce for bit-truncated Ascon-AEAD128
+pub type BitTruncatedAsconAead128Nonce<Bits = U128> = Nonce<BitTruncatedAsconAead128<Bits>>;
+/// Tag for bit-truncated Ascon-AEAD128
+pub type BitTruncatedAsconAead128Tag<Bits = U128> = Tag<BitTruncatedAsconAead128<Bits>>;
+
+impl<Bits: TagBits> KeySizeUser for BitTruncatedAsconAead128<Bits> {
+    type KeySize = U16;
+}
+
+impl<Bits: TagBits> KeyInit for BitTruncatedAsconAead128<Bits> {
+    fn new(key: &Key<Self>) -> Self {
+        Self(Ascon::new(key))
+    }
+}
+
+impl<Bits: TagBits> AeadCore for BitTruncatedAsconAead128<Bits> {
+    type NonceSize = U16;
+    type TagSize = Bits::TagSize;
+    const TAG_POSITION: TagPosition = TagPosition::Postfix;
+}
+
+impl<Bits: TagBits> AeadInOut for BitTruncatedAsconAead128<Bits> {
+    #[inline(always)]
+    fn encrypt_inout_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+    ) -> Result<Tag<Self>, Error> {
+        self.0
+            .encrypt_inout_detached(nonce, associated_data, buffer)
+    }
+
+    #[inline(always)]
+    fn decrypt_inout_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        self.0
+            .decrypt_inout_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
+/// Ascon-AEAD128 with verification of the tag before decryption
+///
+/// Produces the same ciphertexts and tags as [`AsconAead128`]. On decryption, the ciphertext is
+/// first absorbed to verify the tag without writing any output. Only if the tag is valid, the
+/// ciphertext is decrypted in a second pass. Hence, unverified plaintext is never written to the
+/// output buffer at the cost of processing the ciphertext twice.
+///
+/// ```
+/// use ascon_aead::{AsconAead128Key, AsconAead128Nonce, VerifyFirstAsconAead128};
+/// use ascon_aead::aead::{AeadInOut, KeyInit};
+///
+/// let key = AsconAead128Key::from_slice(b"very secret key.");
+/// let nonce = AsconAead128Nonce::from_slice(b"unique nonce 012");
+/// let cipher = VerifyFirstAsconAead128::new(key);
+///
+/// let mut buffer = *b"plaintext message";
+/// let mut tag = cipher
+///     .encrypt_inout_detached(nonce, b"", buffer.as_mut_slice().into())
+///     .unwrap();
+/// let ciphertext = buffer;
+///
+/// tag[0] ^= 1;
+/// assert!(cipher.decrypt_inout_detached(nonce, b"", buffer.as_mut_slice().into(), &tag).is_err());
+/// // the buffer was not touched
+/// assert_eq!(buffer, ciphertext);
+/// ```
+pub struct VerifyFirstAsconAead128(Ascon<Parameters128<U16>>);
+
+impl KeySizeUser for VerifyFirstAsconAead128 {
+    type KeySize = U16;
+}
+
+impl KeyInit for VerifyFirstAsconAead128 {
+    fn new(key: &Key<Self>) -> Self {
+        Self(Ascon::new(key))
+    }
+}
+
+impl AeadCore for VerifyFirstAsconAead128 {
+    type NonceSize = U16;
+    type TagSize = U16;
+    const TAG_POSITION: TagPosition = TagPosition::Postfix;
+}
+
+impl AeadInOut for VerifyFirstAsconAead128 {
+    #[inline(always)]
+    fn encrypt_inout_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+    ) -> Result<Tag<Self>, Error> {
+        self.0
+            .encrypt_inout_detached(nonce, associated_data, buffer)
+    }
+
+    #[inline(always)]
+    fn decrypt_inout_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        self.0
+            .decrypt_inout_detached_verify_first(nonce, associated_data, buffer, tag)
+    }
+}
+
+impl From<AsconAead128> for VerifyFirstAsconAead128 {
+    fn from(cipher: AsconAead128) -> Self {
+        Self(cipher.0)
+    }
+}
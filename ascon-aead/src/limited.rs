@@ -0,0 +1,227 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Ascon-AEAD128 with enforcement of usage limits
+
+use core::fmt;
+
+use aead::{AeadInOut, Error, KeyInit, inout::InOutBuf};
+
+use crate::{AsconAead128, AsconAead128Key, AsconAead128Nonce, AsconAead128Tag};
+
+/// Limits on the usage of a single key
+///
+/// The limits are inclusive, i.e., a limit of `n` messages allows processing exactly `n`
+/// messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UsageLimits {
+    /// Maximal number of encrypted and decrypted messages
+    pub max_messages: u64,
+    /// Maximal number of bytes of associated data and messages
+    pub max_bytes: u64,
+    /// Maximal number of failed decryptions
+    pub max_failed_decryptions: u64,
+}
+
+/// Usage counters of a single key
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UsageCounters {
+    /// Number of encrypted and decrypted messages
+    pub messages: u64,
+    /// Number of bytes of associated data and messages
+    pub bytes: u64,
+    /// Number of failed decryptions
+    pub failed_decryptions: u64,
+}
+
+impl UsageCounters {
+    /// Size of the serialized counters in bytes
+    pub const SIZE: usize = 24;
+
+    /// Serialize the counters as big-endian integers.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[..8].copy_from_slice(&self.messages.to_be_bytes());
+        bytes[8..16].copy_from_slice(&self.bytes.to_be_bytes());
+        bytes[16..].copy_from_slice(&self.failed_decryptions.to_be_bytes());
+        bytes
+    }
+
+    /// Deserialize counters produced by [`UsageCounters::to_bytes`].
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Self {
+        Self {
+            messages: u64::from_be_bytes(bytes[..8].try_into().unwrap()),
+            bytes: u64::from_be_bytes(bytes[8..16].try_into().unwrap()),
+            failed_decryptions: u64::from_be_bytes(bytes[16..].try_into().unwrap()),
+        }
+    }
+}
+
+/// Errors of [`LimitedAsconAead128`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UsageError {
+    /// Encryption or decryption failed
+    Aead(Error),
+    /// The limit on the number of messages was reached
+    MessageLimitReached,
+    /// The limit on the number of bytes was reached
+    ByteLimitReached,
+    /// The limit on the number of failed decryptions was reached
+    FailedDecryptionLimitReached,
+}
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Aead(err) => err.fmt(f),
+            Self::MessageLimitReached => f.write_str("message limit reached"),
+            Self::ByteLimitReached => f.write_str("byte limit reached"),
+            Self::FailedDecryptionLimitReached => f.write_str("failed decryption limit reached"),
+        }
+    }
+}
+
+impl core::error::Error for UsageError {}
+
+impl From<Error> for UsageError {
+    fn from(err: Error) -> Self {
+        Self::Aead(err)
+    }
+}
+
+/// Ascon-AEAD128 with enforcement of usage limits
+///
+/// Tracks the number of messages, the number of processed bytes and the number of failed
+/// decryptions. Once a limit would be exceeded, encryption and decryption fail with a
+/// [`UsageError`] without processing the message. After reaching the limit of failed decryptions,
+/// the key is no longer used at all. The counters can be persisted with
+/// [`LimitedAsconAead128::counters`] and restored with [`LimitedAsconAead128::with_counters`].
+///
+/// ```
+/// use ascon_aead::{AsconAead128Key, AsconAead128Nonce, LimitedAsconAead128, UsageError, UsageLimits};
+///
+/// let key = AsconAead128Key::from_slice(b"very secret key.");
+/// let limits = UsageLimits {
+///     max_messages: 1,
+///     max_bytes: 1 << 20,
+///     max_failed_decryptions: 16,
+/// };
+/// let mut cipher = LimitedAsconAead128::new(key, limits);
+///
+/// let nonce = AsconAead128Nonce::from_slice(b"unique nonce 012");
+/// let mut buffer = *b"plaintext message";
+/// cipher.encrypt_in_place_detached(nonce, b"", &mut buffer).unwrap();
+///
+/// let nonce = AsconAead128Nonce::from_slice(b"unique nonce 013");
+/// assert_eq!(
+///     cipher.encrypt_in_place_detached(nonce, b"", &mut buffer),
+///     Err(UsageError::MessageLimitReached)
+/// );
+/// ```
+pub struct LimitedAsconAead128 {
+    aead: AsconAead128,
+    limits: UsageLimits,
+    counters: UsageCounters,
+}
+
+impl LimitedAsconAead128 {
+    /// Create a new instance with the given key and limits.
+    pub fn new(key: &AsconAead128Key, limits: UsageLimits) -> Self {
+        Self::with_counters(key, limits, UsageCounters::default())
+    }
+
+    /// Create a new instance with the given key and limits and restore the counters.
+    pub fn with_counters(
+        key: &AsconAead128Key,
+        limits: UsageLimits,
+        counters: UsageCounters,
+    ) -> Self {
+        Self {
+            aead: AsconAead128::new(key),
+            limits,
+            counters,
+        }
+    }
+
+    /// Return the limits.
+    pub fn limits(&self) -> UsageLimits {
+        self.limits
+    }
+
+    /// Return the current counters.
+    pub fn counters(&self) -> UsageCounters {
+        self.counters
+    }
+
+    /// Account for a message of the given length if it is within the limits.
+    fn account(&mut self, associated_data: &[u8], buffer_len: usize) -> Result<(), UsageError> {
+        if self.counters.failed_decryptions >= self.limits.max_failed_decryptions {
+            return Err(UsageError::FailedDecryptionLimitReached);
+        }
+        if self.counters.messages >= self.limits.max_messages {
+            return Err(UsageError::MessageLimitReached);
+        }
+        let bytes = (associated_data.len() as u64)
+            .checked_add(buffer_len as u64)
+            .and_then(|len| len.checked_add(self.counters.bytes))
+            .filter(|bytes| *bytes <= self.limits.max_bytes)
+            .ok_or(UsageError::ByteLimitReached)?;
+
+        self.counters.messages += 1;
+        self.counters.bytes = bytes;
+        Ok(())
+    }
+
+    /// Encrypt the data in the provided [`InOutBuf`] and return the tag.
+    pub fn encrypt_inout_detached(
+        &mut self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+    ) -> Result<AsconAead128Tag, UsageError> {
+        self.account(associated_data, buffer.len())?;
+        Ok(self
+            .aead
+            .encrypt_inout_detached(nonce, associated_data, buffer)?)
+    }
+
+    /// Encrypt the data in-place and return the tag.
+    pub fn encrypt_in_place_detached(
+        &mut self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<AsconAead128Tag, UsageError> {
+        self.encrypt_inout_detached(nonce, associated_data, buffer.into())
+    }
+
+    /// Decrypt the data in the provided [`InOutBuf`] and verify the tag.
+    ///
+    /// Failed decryptions are counted towards the limit of failed decryptions.
+    pub fn decrypt_inout_detached(
+        &mut self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+        tag: &AsconAead128Tag,
+    ) -> Result<(), UsageError> {
+        self.account(associated_data, buffer.len())?;
+        self.aead
+            .decrypt_inout_detached(nonce, associated_data, buffer, tag)
+            .inspect_err(|_| self.counters.failed_decryptions += 1)?;
+        Ok(())
+    }
+
+    /// Decrypt the data in-place and verify the tag.
+    ///
+    /// Failed decryptions are counted towards the limit of failed decryptions.
+    pub fn decrypt_in_place_detached(
+        &mut self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &AsconAead128Tag,
+    ) -> Result<(), UsageError> {
+        self.decrypt_inout_detached(nonce, associated_data, buffer.into(), tag)
+    }
+}
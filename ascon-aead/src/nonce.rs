@@ -0,0 +1,214 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Nonce sequences and generators
+//!
+//! Ascon-AEAD128 requires a unique nonce for every message encrypted under the same key. This
+//! module provides the following ways to produce nonces:
+//!
+//! * [`CounterNonceSequence`]: a fixed prefix followed by a big-endian counter,
+//! * [`XorNonceSequence`]: a 64 bit sequence number XORed with a fixed IV as done by TLS 1.3,
+//! * [`RandomNonceGenerator`]: randomly sampled nonces (requires the `rand_core` feature).
+//!
+//! The sequences never repeat a nonce. Once they are exhausted, they return an error.
+//!
+//! ```
+//! use ascon_aead::{AsconAead128, AsconAead128Key};
+//! use ascon_aead::aead::{AeadInOut, KeyInit, array::Array, consts::U8};
+//! use ascon_aead::nonce::{CounterNonceSequence, NonceSequence};
+//!
+//! let cipher = AsconAead128::new(AsconAead128Key::from_slice(b"very secret key."));
+//! let mut nonces = CounterNonceSequence::<U8>::new(&Array(*b"prefix 0"));
+//!
+//! let mut buffer = *b"plaintext message";
+//! let nonce = nonces.next_nonce().unwrap();
+//! cipher
+//!     .encrypt_inout_detached(&nonce, b"", buffer.as_mut_slice().into())
+//!     .unwrap();
+//! assert_eq!(&nonce[..8], b"prefix 0");
+//! assert_eq!(nonces.next_nonce().unwrap()[8..], [0, 0, 0, 0, 0, 0, 0, 1]);
+//! ```
+
+use aead::{
+    Error,
+    array::{Array, ArraySize, typenum::IsLess},
+    consts::{True, U8, U16},
+};
+#[cfg(feature = "rand_core")]
+use aead::{Generate, rand_core::TryCryptoRng};
+
+use crate::AsconAead128Nonce;
+
+/// Sources of unique nonces
+pub trait NonceSequence {
+    /// Return the next nonce.
+    ///
+    /// Fails if no more nonces are available.
+    fn next_nonce(&mut self) -> Result<AsconAead128Nonce, Error>;
+}
+
+/// Nonces consisting of a fixed prefix followed by a big-endian counter
+///
+/// The counter fills the `16 - PrefixSize` bytes following the prefix and starts at 0. Once all
+/// counter values have been used, the sequence is exhausted.
+#[derive(Clone, Debug)]
+pub struct CounterNonceSequence<PrefixSize = U8>
+where
+    PrefixSize: ArraySize + IsLess<U16, Output = True>,
+{
+    prefix: Array<u8, PrefixSize>,
+    // next counter value or `None` if exhausted
+    counter: Option<u128>,
+}
+
+impl<PrefixSize> CounterNonceSequence<PrefixSize>
+where
+    PrefixSize: ArraySize + IsLess<U16, Output = True>,
+{
+    /// Size of the counter in bytes
+    const COUNTER_SIZE: usize = 16 - PrefixSize::USIZE;
+    /// Maximal counter value
+    const MAX_COUNTER: u128 = u128::MAX >> (8 * PrefixSize::USIZE);
+
+    /// Create a new sequence with the given prefix starting at counter 0.
+    pub fn new(prefix: &Array<u8, PrefixSize>) -> Self {
+        Self {
+            prefix: prefix.clone(),
+            counter: Some(0),
+        }
+    }
+
+    /// Create a new sequence with the given prefix starting at the given counter.
+    ///
+    /// This allows resuming a sequence with a counter obtained from [`Self::counter`]. Fails if
+    /// the counter does not fit into the counter bytes.
+    pub fn with_counter(prefix: &Array<u8, PrefixSize>, counter: u128) -> Result<Self, Error> {
+        if counter > Self::MAX_COUNTER {
+            return Err(Error);
+        }
+        Ok(Self {
+            prefix: prefix.clone(),
+            counter: Some(counter),
+        })
+    }
+
+    /// Return the counter value of the next nonce or `None` if the sequence is exhausted.
+    pub fn counter(&self) -> Option<u128> {
+        self.counter
+    }
+}
+
+impl<PrefixSize> NonceSequence for CounterNonceSequence<PrefixSize>
+where
+    PrefixSize: ArraySize + IsLess<U16, Output = True>,
+{
+    fn next_nonce(&mut self) -> Result<AsconAead128Nonce, Error> {
+        let counter = self.counter.ok_or(Error)?;
+        self.counter = if counter == Self::MAX_COUNTER {
+            None
+        } else {
+            Some(counter + 1)
+        };
+
+        let mut nonce = AsconAead128Nonce::default();
+        nonce[..PrefixSize::USIZE].copy_from_slice(&self.prefix);
+        nonce[PrefixSize::USIZE..]
+            .copy_from_slice(&counter.to_be_bytes()[16 - Self::COUNTER_SIZE..]);
+        Ok(nonce)
+    }
+}
+
+/// Nonces derived from a 64 bit sequence number and a fixed IV
+///
+/// As in TLS 1.3, the big-endian sequence number is padded to 16 bytes from the left and XORed
+/// with the IV. The sequence number starts at 0. Once all sequence numbers have been used, the
+/// sequence is exhausted.
+#[derive(Clone, Debug)]
+pub struct XorNonceSequence {
+    iv: AsconAead128Nonce,
+    // next sequence number or `None` if exhausted
+    sequence_number: Option<u64>,
+}
+
+impl XorNonceSequence {
+    /// Create a new sequence with the given IV starting at sequence number 0.
+    pub fn new(iv: &AsconAead128Nonce) -> Self {
+        Self::with_sequence_number(iv, 0)
+    }
+
+    /// Create a new sequence with the given IV starting at the given sequence number.
+    pub fn with_sequence_number(iv: &AsconAead128Nonce, sequence_number: u64) -> Self {
+        Self {
+            iv: *iv,
+            sequence_number: Some(sequence_number),
+        }
+    }
+
+    /// Return the sequence number of the next nonce or `None` if the sequence is exhausted.
+    pub fn sequence_number(&self) -> Option<u64> {
+        self.sequence_number
+    }
+
+    /// Compute the nonce for the given sequence number.
+    ///
+    /// This is intended for receivers that learn the sequence number of a message out of band.
+    pub fn nonce(&self, sequence_number: u64) -> AsconAead128Nonce {
+        let mut nonce = self.iv;
+        nonce[8..]
+            .iter_mut()
+            .zip(sequence_number.to_be_bytes())
+            .for_each(|(n, s)| *n ^= s);
+        nonce
+    }
+}
+
+impl NonceSequence for XorNonceSequence {
+    fn next_nonce(&mut self) -> Result<AsconAead128Nonce, Error> {
+        let sequence_number = self.sequence_number.ok_or(Error)?;
+        self.sequence_number = sequence_number.checked_add(1);
+        Ok(self.nonce(sequence_number))
+    }
+}
+
+/// Randomly sampled nonces
+///
+/// Uniqueness of the nonces only holds with high probability. To keep the probability of a
+/// collision negligible, the number of nonces produced under one key should be kept well below
+/// 2<sup>64</sup>.
+///
+/// ```
+/// # #[cfg(feature = "getrandom")] {
+/// use ascon_aead::nonce::{NonceSequence, RandomNonceGenerator};
+///
+/// let mut nonces = RandomNonceGenerator::default();
+/// assert_ne!(nonces.next_nonce().unwrap(), nonces.next_nonce().unwrap());
+/// # }
+/// ```
+#[cfg(feature = "rand_core")]
+#[derive(Clone, Debug)]
+pub struct RandomNonceGenerator<R> {
+    rng: R,
+}
+
+#[cfg(feature = "rand_core")]
+impl<R: TryCryptoRng> RandomNonceGenerator<R> {
+    /// Create a new generator sampling nonces from the given RNG.
+    pub fn new(rng: R) -> Self {
+        Self { rng }
+    }
+}
+
+#[cfg(feature = "getrandom")]
+impl Default for RandomNonceGenerator<aead::common::getrandom::SysRng> {
+    /// Create a new generator sampling nonces from the system's RNG.
+    fn default() -> Self {
+        Self::new(aead::common::getrandom::SysRng)
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<R: TryCryptoRng> NonceSequence for RandomNonceGenerator<R> {
+    fn next_nonce(&mut self) -> Result<AsconAead128Nonce, Error> {
+        AsconAead128Nonce::try_generate_from_rng(&mut self.rng).map_err(|_| Error)
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Parallel encryption of large buffers
+//!
+//! [`par_seal`] splits a buffer into segments that are encrypted independently on the threads of
+//! the [`rayon`] thread pool. Each segment is encrypted with its own key and nonce:
+//!
+//! - The key of segment `i` is derived with Ascon-CXOF128 from the key, the base nonce and `i`.
+//! - The nonce of segment `i` is the base nonce with the last 8 bytes XORed with `i` (big-endian).
+//!
+//! The output ends with a manifest. Its tag is computed under a separately derived manifest key
+//! and the base nonce and authenticates the segment size, the number of segments, the total
+//! length, the tags of all segments and the associated data:
+//!
+//! ```text
+//! output = segment 0 || tag 0 || ... || segment n-1 || tag n-1 || manifest
+//! manifest = segment size (8 bytes) || segment count (8 bytes) || total length (8 bytes) || tag
+//! ```
+//!
+//! All segments except the last one hold exactly segment size bytes. [`par_open`] verifies the
+//! manifest and all segments and thus rejects missing, duplicated or reordered segments.
+//!
+//! ```
+//! use ascon_aead::{AsconAead128Key, AsconAead128Nonce};
+//! use ascon_aead::parallel::{par_open, par_seal_with_segment_size};
+//!
+//! let key = AsconAead128Key::from_slice(b"very secret key.");
+//! let nonce = AsconAead128Nonce::from_slice(b"unique nonce 012");
+//! let plaintext = vec![0x42u8; 1 << 16];
+//!
+//! let ciphertext = par_seal_with_segment_size(key, nonce, b"", &plaintext, 1 << 12).unwrap();
+//! assert_eq!(par_open(key, nonce, b"", &ciphertext).unwrap(), plaintext);
+//! ```
+
+use aead::{AeadInOut, Error, KeyInit, consts::U16, inout::InOutBuf};
+use alloc::{vec, vec::Vec};
+use ascon_hash::{AsconCxof128, CustomizedInit, ExtendableOutput, Update, XofReader};
+use rayon::prelude::*;
+
+use crate::{
+    AsconAead128, AsconAead128Key, AsconAead128Nonce,
+    asconcore::{AsconIncrementalCore, InternalKey16, Parameters128},
+};
+
+/// Customization string of Ascon-CXOF128 for the key derivation
+const PARALLEL_CUSTOMIZATION: &[u8] = b"Ascon-AEAD128 parallel segments";
+/// Size of the tags in bytes
+const TAG_SIZE: usize = 16;
+/// Size of the manifest in bytes
+pub const MANIFEST_SIZE: usize = 3 * 8 + TAG_SIZE;
+/// Default segment size in bytes
+pub const DEFAULT_SEGMENT_SIZE: usize = 1 << 20;
+
+/// Domain separator of the segment keys
+const SEGMENT_LABEL: u8 = 0;
+/// Domain separator of the manifest key
+const MANIFEST_LABEL: u8 = 1;
+
+/// Key of a single segment or of the manifest
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
+struct DerivedKey([u8; 16]);
+
+impl DerivedKey {
+    /// Derive a key from the key, the base nonce, a label and an index.
+    fn new(key: &AsconAead128Key, nonce: &AsconAead128Nonce, label: u8, index: u64) -> Self {
+        let mut derived_key = [0u8; 16];
+        AsconCxof128::new_customized(PARALLEL_CUSTOMIZATION)
+            .chain(key)
+            .chain(nonce)
+            .chain([label])
+            .chain(index.to_be_bytes())
+            .finalize_xof()
+            .read(&mut derived_key);
+        Self(derived_key)
+    }
+
+    /// Derive the key of the segment with the given index.
+    fn segment(key: &AsconAead128Key, nonce: &AsconAead128Nonce, index: usize) -> Self {
+        Self::new(key, nonce, SEGMENT_LABEL, index as u64)
+    }
+
+    /// Derive the key of the manifest.
+    fn manifest(key: &AsconAead128Key, nonce: &AsconAead128Nonce) -> Self {
+        Self::new(key, nonce, MANIFEST_LABEL, 0)
+    }
+
+    /// Instantiate Ascon-AEAD128 with this key.
+    fn cipher(&self) -> AsconAead128 {
+        AsconAead128::new(&self.0.into())
+    }
+
+    /// Authenticate the manifest, the tags of the segments and the associated data.
+    fn authenticate_manifest<'a>(
+        &self,
+        nonce: &AsconAead128Nonce,
+        manifest: &[u8],
+        tags: impl Iterator<Item = &'a [u8]>,
+        associated_data: &[u8],
+    ) -> Result<AsconIncrementalCore<Parameters128<U16>>, Error> {
+        let mut core = AsconIncrementalCore::<Parameters128<U16>>::new(
+            &InternalKey16::from(&self.0.into()),
+            nonce,
+        );
+        core.process_associated_data(manifest)?;
+        for tag in tags {
+            core.process_associated_data(tag)?;
+        }
+        core.process_associated_data(associated_data)?;
+        Ok(core)
+    }
+}
+
+/// Derive the nonce of the segment with the given index from the base nonce.
+fn segment_nonce(nonce: &AsconAead128Nonce, index: usize) -> AsconAead128Nonce {
+    let mut nonce = *nonce;
+    for (n, i) in nonce[8..].iter_mut().zip((index as u64).to_be_bytes()) {
+        *n ^= i;
+    }
+    nonce
+}
+
+/// Encode the manifest without the tag.
+fn encode_manifest(
+    segment_size: usize,
+    segments: usize,
+    length: usize,
+) -> [u8; MANIFEST_SIZE - TAG_SIZE] {
+    let mut manifest = [0u8; MANIFEST_SIZE - TAG_SIZE];
+    manifest[..8].copy_from_slice(&(segment_size as u64).to_be_bytes());
+    manifest[8..16].copy_from_slice(&(segments as u64).to_be_bytes());
+    manifest[16..].copy_from_slice(&(length as u64).to_be_bytes());
+    manifest
+}
+
+/// Encrypt the plaintext in parallel with the default segment size.
+pub fn par_seal(
+    key: &AsconAead128Key,
+    nonce: &AsconAead128Nonce,
+    associated_data: &[u8],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, Error> {
+    par_seal_with_segment_size(key, nonce, associated_data, plaintext, DEFAULT_SEGMENT_SIZE)
+}
+
+/// Encrypt the plaintext in parallel with the given segment size.
+///
+/// Fails if the segment size is 0 or too large.
+pub fn par_seal_with_segment_size(
+    key: &AsconAead128Key,
+    nonce: &AsconAead128Nonce,
+    associated_data: &[u8],
+    plaintext: &[u8],
+    segment_size: usize,
+) -> Result<Vec<u8>, Error> {
+    if segment_size == 0 || segment_size > usize::MAX - TAG_SIZE {
+        return Err(Error);
+    }
+    let segments = plaintext.len().div_ceil(segment_size);
+    let body_len = segments
+        .checked_mul(TAG_SIZE)
+        .and_then(|len| len.checked_add(plaintext.len()))
+        .ok_or(Error)?;
+    let mut output = vec![0u8; body_len.checked_add(MANIFEST_SIZE).ok_or(Error)?];
+
+    let (body, manifest) = output.split_at_mut(body_len);
+    body.par_chunks_mut(segment_size + TAG_SIZE)
+        .zip(plaintext.par_chunks(segment_size))
+        .enumerate()
+        .try_for_each(|(index, (output, plaintext))| {
+            let (ciphertext, tag) = output.split_at_mut(plaintext.len());
+            let buffer = InOutBuf::new(plaintext, ciphertext).map_err(|_| Error)?;
+            let cipher = DerivedKey::segment(key, nonce, index).cipher();
+            let segment_tag =
+                cipher.encrypt_inout_detached(&segment_nonce(nonce, index), b"", buffer)?;
+            tag.copy_from_slice(&segment_tag);
+            Ok(())
+        })?;
+
+    let (manifest, tag) = manifest.split_at_mut(MANIFEST_SIZE - TAG_SIZE);
+    manifest.copy_from_slice(&encode_manifest(segment_size, segments, plaintext.len()));
+    let tags = body
+        .chunks(segment_size + TAG_SIZE)
+        .map(|segment| &segment[segment.len() - TAG_SIZE..]);
+    let core = DerivedKey::manifest(key, nonce).authenticate_manifest(
+        nonce,
+        manifest,
+        tags,
+        associated_data,
+    )?;
+    tag.copy_from_slice(&core.finalize());
+    Ok(output)
+}
+
+/// Decrypt and verify the output of [`par_seal`] in parallel.
+///
+/// Fails if the manifest or any segment is not authentic.
+pub fn par_open(
+    key: &AsconAead128Key,
+    nonce: &AsconAead128Nonce,
+    associated_data: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let body_len = ciphertext.len().checked_sub(MANIFEST_SIZE).ok_or(Error)?;
+    let (body, manifest) = ciphertext.split_at(body_len);
+    let (manifest, tag) = manifest.split_at(MANIFEST_SIZE - TAG_SIZE);
+
+    let decode = |index: usize| {
+        usize::try_from(u64::from_be_bytes(
+            manifest[8 * index..8 * (index + 1)].try_into().unwrap(),
+        ))
+        .map_err(|_| Error)
+    };
+    let segment_size = decode(0)?;
+    let segments = decode(1)?;
+    let length = decode(2)?;
+    if segment_size == 0
+        || segment_size > usize::MAX - TAG_SIZE
+        || segments != length.div_ceil(segment_size)
+        || segments
+            .checked_mul(TAG_SIZE)
+            .and_then(|len| len.checked_add(length))
+            != Some(body_len)
+    {
+        return Err(Error);
+    }
+
+    let tags = body
+        .chunks(segment_size + TAG_SIZE)
+        .map(|segment| &segment[segment.len() - TAG_SIZE..]);
+    DerivedKey::manifest(key, nonce)
+        .authenticate_manifest(nonce, manifest, tags, associated_data)?
+        .verify_truncated(tag)?;
+
+    let mut plaintext = vec![0u8; length];
+    plaintext
+        .par_chunks_mut(segment_size)
+        .zip(body.par_chunks(segment_size + TAG_SIZE))
+        .enumerate()
+        .try_for_each(|(index, (plaintext, segment))| {
+            let (ciphertext, tag) = segment.split_at(plaintext.len());
+            let buffer = InOutBuf::new(ciphertext, plaintext).map_err(|_| Error)?;
+            let cipher = DerivedKey::segment(key, nonce, index).cipher();
+            cipher.decrypt_inout_detached(
+                &segment_nonce(nonce, index),
+                b"",
+                buffer,
+                tag.try_into().map_err(|_| Error)?,
+            )
+        })?;
+    Ok(plaintext)
+}
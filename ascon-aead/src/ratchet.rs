@@ -0,0 +1,301 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Ascon-AEAD128 with a symmetric key ratchet
+
+use aead::{AeadInOut, Error, KeyInit, inout::InOutBuf};
+use ascon_hash::{AsconCxof128, CustomizedInit, ExtendableOutput, Update, XofReader};
+
+use crate::{AsconAead128, AsconAead128Key, AsconAead128Nonce, AsconAead128Tag};
+
+/// Customization string of Ascon-CXOF128 for the key derivation
+const RATCHET_CUSTOMIZATION: &[u8] = b"Ascon-AEAD128 key ratchet";
+
+/// Key of an epoch
+///
+/// The `ratchet` feature enables `zeroize`, so keys of old epochs are always wiped when dropped.
+#[derive(Clone, zeroize::Zeroize, zeroize::ZeroizeOnDrop)]
+struct EpochKey([u8; 16]);
+
+impl EpochKey {
+    /// Derive the key of the next epoch.
+    fn next(&self) -> Self {
+        let mut key = [0u8; 16];
+        AsconCxof128::new_customized(RATCHET_CUSTOMIZATION)
+            .chain(self.0)
+            .finalize_xof()
+            .read(&mut key);
+        Self(key)
+    }
+
+    /// Instantiate Ascon-AEAD128 with this key.
+    fn cipher(&self) -> AsconAead128 {
+        AsconAead128::new(&self.0.into())
+    }
+}
+
+/// Policy for automatic rekeying on encryption
+///
+/// The key is ratcheted forward before encrypting a message, if the current epoch has reached the
+/// maximal number of messages or if the message would exceed the maximal number of bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RatchetPolicy {
+    /// Maximal number of messages per epoch
+    pub max_messages: u64,
+    /// Maximal number of bytes of associated data and messages per epoch
+    pub max_bytes: u64,
+}
+
+impl Default for RatchetPolicy {
+    /// Only ratchet on demand.
+    fn default() -> Self {
+        Self {
+            max_messages: u64::MAX,
+            max_bytes: u64::MAX,
+        }
+    }
+}
+
+/// Ascon-AEAD128 with a symmetric key ratchet
+///
+/// The key of the next epoch is derived from the key of the current epoch with Ascon-CXOF128 and
+/// the key of the current epoch is dropped. Keys of previous epochs are thus not recoverable from
+/// the current state. The sender ratchets forward based on a [`RatchetPolicy`] or on demand with
+/// [`RatchetingAsconAead128::ratchet`], and transmits the epoch of each message alongside the
+/// ciphertext.
+///
+/// The receiver follows the epochs of the received messages. It accepts messages of a later epoch
+/// as long as at most `MAX_SKIPPED` epochs lie between its current epoch and the epoch of the
+/// message. Hence, a receiver with `MAX_SKIPPED = 0` can still follow a sender ratcheting one
+/// epoch at a time. Independently, the receiver retains the keys of up to `MAX_SKIPPED` previous
+/// epochs to handle out-of-order delivery. The receiver only moves to a new epoch after
+/// successfully decrypting a message of that epoch. Retained keys are dropped once they are
+/// evicted by newer epochs or by calling [`RatchetingAsconAead128::forget_previous_epochs`].
+///
+/// ```
+/// use ascon_aead::{AsconAead128Key, AsconAead128Nonce, RatchetPolicy, RatchetingAsconAead128};
+///
+/// let key = AsconAead128Key::from_slice(b"very secret key.");
+/// let policy = RatchetPolicy {
+///     max_messages: 1,
+///     ..Default::default()
+/// };
+/// let mut sender = RatchetingAsconAead128::<4>::new(key, policy);
+/// let mut receiver = RatchetingAsconAead128::<4>::new(key, RatchetPolicy::default());
+///
+/// let nonce = AsconAead128Nonce::from_slice(b"unique nonce 012");
+/// let mut first = *b"first message";
+/// let (first_epoch, first_tag) = sender.encrypt_in_place_detached(nonce, b"", &mut first).unwrap();
+/// let mut second = *b"second message";
+/// let (second_epoch, second_tag) = sender.encrypt_in_place_detached(nonce, b"", &mut second).unwrap();
+/// assert_eq!((first_epoch, second_epoch), (0, 1));
+///
+/// // out-of-order delivery
+/// receiver.decrypt_in_place_detached(second_epoch, nonce, b"", &mut second, &second_tag).unwrap();
+/// receiver.decrypt_in_place_detached(first_epoch, nonce, b"", &mut first, &first_tag).unwrap();
+/// assert_eq!(&first, b"first message");
+/// assert_eq!(&second, b"second message");
+/// ```
+pub struct RatchetingAsconAead128<const MAX_SKIPPED: usize = 8> {
+    key: EpochKey,
+    cipher: AsconAead128,
+    epoch: u64,
+    messages: u64,
+    bytes: u64,
+    policy: RatchetPolicy,
+    // ciphers of previous epochs, ordered from newest to oldest
+    previous: [Option<(u64, AsconAead128)>; MAX_SKIPPED],
+}
+
+impl<const MAX_SKIPPED: usize> RatchetingAsconAead128<MAX_SKIPPED> {
+    /// Create a new instance with the given initial key and rekeying policy.
+    pub fn new(key: &AsconAead128Key, policy: RatchetPolicy) -> Self {
+        let key = EpochKey((*key).into());
+        Self {
+            cipher: key.cipher(),
+            key,
+            epoch: 0,
+            messages: 0,
+            bytes: 0,
+            policy,
+            previous: [const { None }; MAX_SKIPPED],
+        }
+    }
+
+    /// Return the current epoch.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Ratchet the key forward to the next epoch.
+    ///
+    /// The key of the current epoch is dropped. Fails if the epoch counter is exhausted.
+    pub fn ratchet(&mut self) -> Result<(), Error> {
+        self.epoch = self.epoch.checked_add(1).ok_or(Error)?;
+        self.key = self.key.next();
+        self.cipher = self.key.cipher();
+        self.messages = 0;
+        self.bytes = 0;
+        Ok(())
+    }
+
+    /// Drop the retained keys of all previous epochs.
+    pub fn forget_previous_epochs(&mut self) {
+        self.previous = [const { None }; MAX_SKIPPED];
+    }
+
+    /// Encrypt the data in the provided [`InOutBuf`] and return the epoch and the tag.
+    ///
+    /// Ratchets forward first if required by the policy.
+    pub fn encrypt_inout_detached(
+        &mut self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+    ) -> Result<(u64, AsconAead128Tag), Error> {
+        let len = (associated_data.len() as u64)
+            .checked_add(buffer.len() as u64)
+            .ok_or(Error)?;
+        let exceeds_bytes = self
+            .bytes
+            .checked_add(len)
+            .is_none_or(|bytes| bytes > self.policy.max_bytes);
+        if self.messages >= self.policy.max_messages || (self.messages > 0 && exceeds_bytes) {
+            self.ratchet()?;
+        }
+
+        let tag = self
+            .cipher
+            .encrypt_inout_detached(nonce, associated_data, buffer)?;
+        self.messages += 1;
+        self.bytes = self.bytes.saturating_add(len);
+        Ok((self.epoch, tag))
+    }
+
+    /// Encrypt the data in-place and return the epoch and the tag.
+    ///
+    /// Ratchets forward first if required by the policy.
+    pub fn encrypt_in_place_detached(
+        &mut self,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(u64, AsconAead128Tag), Error> {
+        self.encrypt_inout_detached(nonce, associated_data, buffer.into())
+    }
+
+    /// Decrypt the data of the given epoch in the provided [`InOutBuf`] and verify the tag.
+    ///
+    /// Fails if more than `MAX_SKIPPED` epochs lie between the current epoch and the given epoch or
+    /// if the key of the epoch is no longer available. On successful decryption of a message of a future epoch, the receiver
+    /// moves to that epoch.
+    pub fn decrypt_inout_detached(
+        &mut self,
+        epoch: u64,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+        tag: &AsconAead128Tag,
+    ) -> Result<(), Error> {
+        if epoch == self.epoch {
+            return self
+                .cipher
+                .decrypt_inout_detached(nonce, associated_data, buffer, tag);
+        }
+
+        if epoch < self.epoch {
+            let (_, cipher) = self
+                .previous
+                .iter()
+                .flatten()
+                .find(|(previous_epoch, _)| *previous_epoch == epoch)
+                .ok_or(Error)?;
+            return cipher.decrypt_inout_detached(nonce, associated_data, buffer, tag);
+        }
+
+        // number of epochs between the current epoch and the epoch of the message
+        let skipped = epoch - self.epoch - 1;
+        if skipped > MAX_SKIPPED as u64 {
+            return Err(Error);
+        }
+        let mut key = self.key.next();
+        for _ in 0..skipped {
+            key = key.next();
+        }
+        let cipher = key.cipher();
+        cipher.decrypt_inout_detached(nonce, associated_data, buffer, tag)?;
+
+        // move to the new epoch and retain the keys of the epochs in between
+        let mut previous_key = self.key.clone();
+        for previous_epoch in self.epoch..epoch {
+            if previous_epoch != self.epoch {
+                previous_key = previous_key.next();
+            }
+            if MAX_SKIPPED > 0 {
+                self.previous.rotate_right(1);
+                self.previous[0] = Some((previous_epoch, previous_key.cipher()));
+            }
+        }
+        self.key = key;
+        self.cipher = cipher;
+        self.epoch = epoch;
+        self.messages = 0;
+        self.bytes = 0;
+        Ok(())
+    }
+
+    /// Decrypt the data of the given epoch in-place and verify the tag.
+    ///
+    /// See [`RatchetingAsconAead128::decrypt_inout_detached`] for details.
+    pub fn decrypt_in_place_detached(
+        &mut self,
+        epoch: u64,
+        nonce: &AsconAead128Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &AsconAead128Tag,
+    ) -> Result<(), Error> {
+        self.decrypt_inout_detached(epoch, nonce, associated_data, buffer.into(), tag)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+
+    #[test]
+    fn keys_are_wiped() {
+        assert_zeroize_on_drop::<EpochKey>();
+        // key of the ciphers of the current and previous epochs
+        assert_zeroize_on_drop::<crate::asconcore::InternalKey16>();
+    }
+
+    #[test]
+    fn old_keys_are_dropped() {
+        let key = AsconAead128Key::from(*b"very secret key.");
+        let nonce = AsconAead128Nonce::from(*b"unique nonce 012");
+        let mut sender = RatchetingAsconAead128::<2>::new(&key, RatchetPolicy::default());
+        let initial = sender.key.0;
+        sender.ratchet().unwrap();
+        sender.ratchet().unwrap();
+        assert_ne!(sender.key.0, initial);
+        assert_ne!(sender.key.0, EpochKey(initial).next().0);
+        // the sender never retains keys of previous epochs
+        assert!(sender.previous.iter().all(Option::is_none));
+
+        let mut buffer = [0u8; 8];
+        let (epoch, tag) = sender
+            .encrypt_in_place_detached(&nonce, b"", &mut buffer)
+            .unwrap();
+        let mut receiver = RatchetingAsconAead128::<2>::new(&key, RatchetPolicy::default());
+        receiver
+            .decrypt_in_place_detached(epoch, &nonce, b"", &mut buffer, &tag)
+            .unwrap();
+        assert_eq!(receiver.key.0, sender.key.0);
+        assert!(receiver.previous.iter().all(Option::is_some));
+        receiver.forget_previous_epochs();
+        assert!(receiver.previous.iter().all(Option::is_none));
+    }
+}
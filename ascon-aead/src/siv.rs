@@ -0,0 +1,140 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Nonce-misuse-resistant Ascon-SIV
+
+use core::marker::PhantomData;
+
+use aead::{
+    AeadCore, AeadInOut, Error, Key, KeyInit, KeySizeUser, Nonce, Tag, TagPosition,
+    array::{Array, ArraySize},
+    consts::{U16, U32},
+    inout::InOutBuf,
+};
+use ascon_mac::{AsconMac, Mac};
+use subtle::ConstantTimeEq;
+
+use crate::asconcore::{AsconCore, InternalKey16, Parameters128};
+
+/// Ascon-SIV
+///
+/// Synthetic IV mode built from Ascon-Mac and the keystream of Ascon-AEAD128. The first half of the
+/// 256 bit key is used to compute Ascon-Mac over the length-prefixed nonce, the length-prefixed
+/// associated data and the plaintext. The resulting 128 bit tag serves as synthetic IV and is used
+/// as nonce to encrypt the plaintext with the second half of the key.
+///
+/// Reusing a nonce only reveals whether the same associated data and plaintext were encrypted
+/// twice. With `NonceSize = U0`, encryption is deterministic.
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use ascon_aead::{AsconSiv, AsconSivKey};
+/// use ascon_aead::aead::{Aead, KeyInit, Nonce, consts::U0};
+///
+/// let key = AsconSivKey::from_slice(b"a very secret key of 256 bits!!!");
+/// let cipher = AsconSiv::<U0>::new(key);
+/// let nonce = Nonce::<AsconSiv<U0>>::default();
+///
+/// let ciphertext = cipher.encrypt(&nonce, b"plaintext message".as_ref()).unwrap();
+/// assert_eq!(ciphertext, cipher.encrypt(&nonce, b"plaintext message".as_ref()).unwrap());
+/// let plaintext = cipher.decrypt(&nonce, ciphertext.as_ref()).unwrap();
+/// assert_eq!(&plaintext, b"plaintext message");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AsconSiv<NonceSize: ArraySize = U16> {
+    mac: AsconMac,
+    key: InternalKey16,
+    nonce_size: PhantomData<NonceSize>,
+}
+/// Key for Ascon-SIV
+pub type AsconSivKey = Key<AsconSiv>;
+/// Nonce for Ascon-SIV
+pub type AsconSivNonce<NonceSize = U16> = Nonce<AsconSiv<NonceSize>>;
+/// Tag for Ascon-SIV
+pub type AsconSivTag = Tag<AsconSiv>;
+
+impl<NonceSize: ArraySize> AsconSiv<NonceSize> {
+    /// Compute the synthetic IV.
+    fn synthetic_iv(
+        &self,
+        nonce: &[u8],
+        associated_data: &[u8],
+        plaintext: &[u8],
+    ) -> Array<u8, U16> {
+        let mut mac = self.mac.clone();
+        mac.update(&(nonce.len() as u64).to_le_bytes());
+        mac.update(nonce);
+        mac.update(&(associated_data.len() as u64).to_le_bytes());
+        mac.update(associated_data);
+        mac.update(plaintext);
+        mac.finalize().into_bytes()
+    }
+}
+
+impl<NonceSize: ArraySize> KeySizeUser for AsconSiv<NonceSize> {
+    type KeySize = U32;
+}
+
+impl<NonceSize: ArraySize> KeyInit for AsconSiv<NonceSize> {
+    fn new(key: &Key<Self>) -> Self {
+        let (mac_key, enc_key) = key.split_ref::<U16>();
+        Self {
+            mac: AsconMac::new(mac_key),
+            key: InternalKey16::from(enc_key),
+            nonce_size: PhantomData,
+        }
+    }
+}
+
+impl<NonceSize: ArraySize> AeadCore for AsconSiv<NonceSize> {
+    type NonceSize = NonceSize;
+    type TagSize = U16;
+    const TAG_POSITION: TagPosition = TagPosition::Prefix;
+}
+
+impl<NonceSize: ArraySize> AeadInOut for AsconSiv<NonceSize> {
+    fn encrypt_inout_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: InOutBuf<'_, '_, u8>,
+    ) -> Result<Tag<Self>, Error> {
+        if (buffer.len() as u64)
+            .checked_add(associated_data.len() as u64)
+            .is_none()
+        {
+            return Err(Error);
+        }
+
+        let siv = self.synthetic_iv(nonce, associated_data, buffer.get_in());
+        let mut core = AsconCore::<Parameters128<U16>>::new(&self.key, &siv);
+        core.encrypt_inout_untagged(buffer);
+        Ok(siv)
+    }
+
+    fn decrypt_inout_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        mut buffer: InOutBuf<'_, '_, u8>,
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        if (buffer.len() as u64)
+            .checked_add(associated_data.len() as u64)
+            .is_none()
+        {
+            return Err(Error);
+        }
+
+        let mut core = AsconCore::<Parameters128<U16>>::new(&self.key, tag);
+        core.decrypt_inout_untagged(buffer.reborrow());
+        let siv = self.synthetic_iv(nonce, associated_data, buffer.get_out());
+        if bool::from(siv.ct_eq(tag)) {
+            Ok(())
+        } else {
+            buffer.get_out().fill(0);
+            Err(Error)
+        }
+    }
+}
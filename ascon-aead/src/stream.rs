@@ -0,0 +1,231 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! STREAM online authenticated encryption
+//!
+//! Implementation of the STREAM construction by Hoang, Reyhanitabar, Rogaway and Vizár from
+//! ["Online Authenticated-Encryption and its Nonce-Reuse Misuse-Resistance"][1]. A message is
+//! split into segments which are encrypted separately. The nonce of each segment is derived from a
+//! nonce prefix, a 32 bit big-endian segment counter and a flag marking the last segment:
+//!
+//! ```text
+//! nonce = prefix (11 bytes) || counter (4 bytes) || last segment flag (1 byte)
+//! ```
+//!
+//! Reordering, duplicating or dropping segments as well as truncating the stream is detected on
+//! decryption. A stream is only complete if [`StreamDecryptor::decrypt_last`] (or
+//! [`StreamDecryptor::decrypt_last_in_place`]) succeeded.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use ascon_aead::{AsconAead128, AsconAead128Key};
+//! use ascon_aead::stream::{StreamDecryptor, StreamEncryptor, StreamNoncePrefix};
+//!
+//! let key = AsconAead128Key::from_slice(b"very secret key.");
+//! // 88 bits; unique per stream
+//! let nonce_prefix = StreamNoncePrefix::from_slice(b"unique 0123");
+//!
+//! let mut encryptor = StreamEncryptor::<AsconAead128>::new(key, nonce_prefix);
+//! let first = encryptor.encrypt_next(b"first segment".as_ref()).unwrap();
+//! let last = encryptor.encrypt_last(b"last segment".as_ref()).unwrap();
+//!
+//! let mut decryptor = StreamDecryptor::<AsconAead128>::new(key, nonce_prefix);
+//! assert_eq!(decryptor.decrypt_next(first.as_slice()).unwrap(), b"first segment");
+//! assert_eq!(decryptor.decrypt_last(last.as_slice()).unwrap(), b"last segment");
+//! # }
+//! ```
+//!
+//! [1]: https://eprint.iacr.org/2015/189
+
+#[cfg(feature = "alloc")]
+use aead::{Aead, Payload};
+use aead::{
+    AeadInOut, Buffer, Error, Key, KeyInit, Nonce,
+    array::Array,
+    consts::{U11, U16},
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Nonce prefix for STREAM
+pub type StreamNoncePrefix = Array<u8, U11>;
+
+/// Shared state of STREAM encryptors and decryptors
+struct Stream<A: AeadInOut<NonceSize = U16>> {
+    aead: A,
+    nonce_prefix: StreamNoncePrefix,
+    counter: u32,
+}
+
+impl<A: AeadInOut<NonceSize = U16>> Stream<A> {
+    /// Compute the nonce for the current segment.
+    fn nonce(&self, last: bool) -> Nonce<A> {
+        let mut nonce = Nonce::<A>::default();
+        nonce[..11].copy_from_slice(&self.nonce_prefix);
+        nonce[11..15].copy_from_slice(&self.counter.to_be_bytes());
+        nonce[15] = last as u8;
+        nonce
+    }
+
+    /// Compute the nonce for the current segment if it is not the last one.
+    ///
+    /// The maximal counter value is reserved for the last segment.
+    fn next_nonce(&self) -> Result<Nonce<A>, Error> {
+        if self.counter == u32::MAX {
+            Err(Error)
+        } else {
+            Ok(self.nonce(false))
+        }
+    }
+}
+
+/// Encryptor for the STREAM construction
+pub struct StreamEncryptor<A: AeadInOut<NonceSize = U16>>(Stream<A>);
+
+impl<A: AeadInOut<NonceSize = U16>> StreamEncryptor<A> {
+    /// Create a new encryptor from a key and a nonce prefix.
+    pub fn new(key: &Key<A>, nonce_prefix: &StreamNoncePrefix) -> Self
+    where
+        A: KeyInit,
+    {
+        Self::from_aead(A::new(key), nonce_prefix)
+    }
+
+    /// Create a new encryptor from an AEAD instance and a nonce prefix.
+    pub fn from_aead(aead: A, nonce_prefix: &StreamNoncePrefix) -> Self {
+        Self(Stream {
+            aead,
+            nonce_prefix: *nonce_prefix,
+            counter: 0,
+        })
+    }
+
+    /// Encrypt the next segment in-place.
+    ///
+    /// Fails if the maximal number of segments has been reached.
+    pub fn encrypt_next_in_place(
+        &mut self,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let nonce = self.0.next_nonce()?;
+        self.0
+            .aead
+            .encrypt_in_place(&nonce, associated_data, buffer)?;
+        self.0.counter += 1;
+        Ok(())
+    }
+
+    /// Encrypt the last segment in-place.
+    pub fn encrypt_last_in_place(
+        self,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.0
+            .aead
+            .encrypt_in_place(&self.0.nonce(true), associated_data, buffer)
+    }
+
+    /// Encrypt the next segment.
+    ///
+    /// Fails if the maximal number of segments has been reached.
+    #[cfg(feature = "alloc")]
+    pub fn encrypt_next<'msg, 'aad>(
+        &mut self,
+        payload: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let nonce = self.0.next_nonce()?;
+        let ciphertext = self.0.aead.encrypt(&nonce, payload)?;
+        self.0.counter += 1;
+        Ok(ciphertext)
+    }
+
+    /// Encrypt the last segment.
+    #[cfg(feature = "alloc")]
+    pub fn encrypt_last<'msg, 'aad>(
+        self,
+        payload: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        self.0.aead.encrypt(&self.0.nonce(true), payload)
+    }
+}
+
+/// Decryptor for the STREAM construction
+///
+/// Segments have to be decrypted in the order they were encrypted. A failed decryption does not
+/// advance the decryptor.
+pub struct StreamDecryptor<A: AeadInOut<NonceSize = U16>>(Stream<A>);
+
+impl<A: AeadInOut<NonceSize = U16>> StreamDecryptor<A> {
+    /// Create a new decryptor from a key and a nonce prefix.
+    pub fn new(key: &Key<A>, nonce_prefix: &StreamNoncePrefix) -> Self
+    where
+        A: KeyInit,
+    {
+        Self::from_aead(A::new(key), nonce_prefix)
+    }
+
+    /// Create a new decryptor from an AEAD instance and a nonce prefix.
+    pub fn from_aead(aead: A, nonce_prefix: &StreamNoncePrefix) -> Self {
+        Self(Stream {
+            aead,
+            nonce_prefix: *nonce_prefix,
+            counter: 0,
+        })
+    }
+
+    /// Decrypt the next segment in-place.
+    ///
+    /// Fails if the segment is not authentic, is out of order, or is the last segment.
+    pub fn decrypt_next_in_place(
+        &mut self,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let nonce = self.0.next_nonce()?;
+        self.0
+            .aead
+            .decrypt_in_place(&nonce, associated_data, buffer)?;
+        self.0.counter += 1;
+        Ok(())
+    }
+
+    /// Decrypt the last segment in-place.
+    ///
+    /// Fails if the segment is not authentic, is out of order, or is not the last segment.
+    pub fn decrypt_last_in_place(
+        self,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.0
+            .aead
+            .decrypt_in_place(&self.0.nonce(true), associated_data, buffer)
+    }
+
+    /// Decrypt the next segment.
+    ///
+    /// Fails if the segment is not authentic, is out of order, or is the last segment.
+    #[cfg(feature = "alloc")]
+    pub fn decrypt_next<'msg, 'aad>(
+        &mut self,
+        payload: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let nonce = self.0.next_nonce()?;
+        let plaintext = self.0.aead.decrypt(&nonce, payload)?;
+        self.0.counter += 1;
+        Ok(plaintext)
+    }
+
+    /// Decrypt the last segment.
+    ///
+    /// Fails if the segment is not authentic, is out of order, or is not the last segment.
+    #[cfg(feature = "alloc")]
+    pub fn decrypt_last<'msg, 'aad>(
+        self,
+        payload: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        self.0.aead.decrypt(&self.0.nonce(true), payload)
+    }
+}
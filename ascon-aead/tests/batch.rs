@@ -0,0 +1,117 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use ascon_aead::{
+    AsconAead128, AsconAead128Key, AsconAead128Nonce, Error,
+    aead::{AeadInOut, KeyInit},
+    batch::BatchItem,
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+
+fn nonce(i: usize) -> AsconAead128Nonce {
+    let mut nonce = AsconAead128Nonce::from(*b"unique nonce 000");
+    nonce[..8].copy_from_slice(&(i as u64).to_le_bytes());
+    nonce
+}
+
+fn matches_one_shot<const N: usize>() {
+    let cipher = AsconAead128::new(&AsconAead128Key::from(*KEY));
+    // different lengths of associated data and messages, including more messages than lanes
+    let count = 23;
+    let nonces: Vec<_> = (0..count).map(nonce).collect();
+    let associated_data: Vec<Vec<u8>> = (0..count).map(|i| vec![i as u8; (i * 7) % 37]).collect();
+    let messages: Vec<Vec<u8>> = (0..count)
+        .map(|i| (0..(i * 13) % 41).map(|b| b as u8).collect())
+        .collect();
+
+    let mut buffers = messages.clone();
+    let mut items: Vec<_> = buffers
+        .iter_mut()
+        .enumerate()
+        .map(|(i, buffer)| BatchItem::new(&nonces[i], &associated_data[i], buffer))
+        .collect();
+    cipher.encrypt_batch_with_lanes::<N>(&mut items).unwrap();
+    let tags: Vec<_> = items.iter().map(|item| item.tag).collect();
+    drop(items);
+
+    for i in 0..count {
+        let mut expected = messages[i].clone();
+        let expected_tag = cipher
+            .encrypt_inout_detached(
+                &nonces[i],
+                &associated_data[i],
+                expected.as_mut_slice().into(),
+            )
+            .unwrap();
+        assert_eq!(buffers[i], expected);
+        assert_eq!(tags[i], expected_tag);
+    }
+
+    let mut items: Vec<_> = buffers
+        .iter_mut()
+        .enumerate()
+        .map(|(i, buffer)| {
+            let mut item = BatchItem::new(&nonces[i], &associated_data[i], buffer);
+            item.tag = tags[i];
+            item
+        })
+        .collect();
+    let mut results = vec![Err(Error); count];
+    cipher
+        .decrypt_batch_with_lanes::<N>(&mut items, &mut results)
+        .unwrap();
+    drop(items);
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(buffers, messages);
+}
+
+#[test]
+fn matches_one_shot_lanes() {
+    matches_one_shot::<{ ascon_aead::batch::LANES }>();
+    matches_one_shot::<1>();
+    matches_one_shot::<4>();
+    matches_one_shot::<5>();
+    matches_one_shot::<16>();
+}
+
+#[test]
+fn invalid_tags() {
+    let cipher = AsconAead128::new(&AsconAead128Key::from(*KEY));
+    let count = 6;
+    let nonces: Vec<_> = (0..count).map(nonce).collect();
+    let mut buffers: Vec<Vec<u8>> = (0..count).map(|i| vec![i as u8; 10 + i]).collect();
+    let messages = buffers.clone();
+
+    let mut items: Vec<_> = buffers
+        .iter_mut()
+        .enumerate()
+        .map(|(i, buffer)| BatchItem::new(&nonces[i], b"ad", buffer))
+        .collect();
+    cipher.encrypt_batch(&mut items).unwrap();
+    items[1].tag[0] ^= 1;
+    items[4].associated_data = b"da";
+
+    let mut results = vec![Ok(()); count];
+    cipher.decrypt_batch(&mut items, &mut results).unwrap();
+    drop(items);
+    for i in 0..count {
+        if i == 1 || i == 4 {
+            assert!(results[i].is_err());
+            assert!(buffers[i].iter().all(|b| *b == 0));
+        } else {
+            assert!(results[i].is_ok());
+            assert_eq!(buffers[i], messages[i]);
+        }
+    }
+}
+
+#[test]
+fn mismatched_results() {
+    let cipher = AsconAead128::new(&AsconAead128Key::from(*KEY));
+    let nonce = nonce(0);
+    let mut buffer = *b"message";
+    let mut items = [BatchItem::new(&nonce, b"", &mut buffer)];
+    assert!(cipher.decrypt_batch(&mut items, &mut []).is_err());
+    assert!(cipher.encrypt_batch(&mut []).is_ok());
+}
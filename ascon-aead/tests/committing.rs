@@ -0,0 +1,76 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(all(feature = "committing", feature = "alloc"))]
+
+use ascon_aead::{
+    AsconAead128, CommittingAsconAead128, CommittingAsconAead128Key, CommittingAsconAead128Nonce,
+    KeyCommittingAead,
+    aead::{Aead, AeadCore, AeadInOut, KeyInit, Payload, array::typenum::Unsigned},
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE: &[u8; 16] = b"unique nonce 012";
+
+#[test]
+fn roundtrip() {
+    let cipher = CommittingAsconAead128::new(&CommittingAsconAead128Key::from(*KEY));
+    let nonce = CommittingAsconAead128Nonce::from(*NONCE);
+    for len in 0..=40 {
+        let msg: Vec<u8> = (0..len).collect();
+        let payload = Payload {
+            msg: &msg,
+            aad: b"associated data",
+        };
+        let ciphertext = cipher.encrypt(&nonce, payload).unwrap();
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: b"associated data",
+        };
+        assert_eq!(cipher.decrypt(&nonce, payload).unwrap(), msg);
+    }
+}
+
+#[test]
+fn extends_ascon_aead128() {
+    let cipher = CommittingAsconAead128::new(&CommittingAsconAead128Key::from(*KEY));
+    let nonce = CommittingAsconAead128Nonce::from(*NONCE);
+    let ciphertext = cipher.encrypt(&nonce, b"plaintext".as_ref()).unwrap();
+    let expected = AsconAead128::new(KEY.into())
+        .encrypt(NONCE.into(), b"plaintext".as_ref())
+        .unwrap();
+
+    let commitment_size = <CommittingAsconAead128 as KeyCommittingAead>::CommitmentSize::USIZE;
+    assert_eq!(
+        <CommittingAsconAead128 as AeadCore>::TagSize::USIZE,
+        16 + commitment_size
+    );
+    assert_eq!(ciphertext.len(), expected.len() + commitment_size);
+    assert_eq!(ciphertext[..expected.len()], expected);
+}
+
+#[test]
+fn wrong_key() {
+    let cipher = CommittingAsconAead128::new(&CommittingAsconAead128Key::from(*KEY));
+    let other_cipher = CommittingAsconAead128::new_from_slice(b"other secret key").unwrap();
+    let nonce = CommittingAsconAead128Nonce::from(*NONCE);
+    let ciphertext = cipher.encrypt(&nonce, b"plaintext".as_ref()).unwrap();
+    assert!(other_cipher.decrypt(&nonce, ciphertext.as_ref()).is_err());
+}
+
+#[test]
+fn bad_commitment() {
+    let cipher = CommittingAsconAead128::new(&CommittingAsconAead128Key::from(*KEY));
+    let nonce = CommittingAsconAead128Nonce::from(*NONCE);
+    let mut buffer = *b"plaintext message";
+    let mut tag = cipher
+        .encrypt_inout_detached(&nonce, b"", buffer.as_mut_slice().into())
+        .unwrap();
+    *tag.last_mut().unwrap() ^= 1;
+    assert!(
+        cipher
+            .decrypt_inout_detached(&nonce, b"", buffer.as_mut_slice().into(), &tag)
+            .is_err()
+    );
+    assert!(buffer.iter().all(|b| *b == 0));
+}
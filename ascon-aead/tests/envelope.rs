@@ -0,0 +1,238 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+#![cfg(feature = "alloc")]
+
+use ascon_aead::{
+    AsconAead128, AsconAead128Key, AsconAead128Nonce, Error,
+    aead::{AeadInOut, KeyInit},
+    envelope::{
+        Algorithm, EnvelopeError, Header, MAGIC, open, open_in_place, seal_in_place, seal_with,
+    },
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE: &[u8; 16] = b"unique nonce 012";
+const PLAINTEXT: &[u8] = b"plaintext message";
+
+fn header(tag_size: u8, key_id: Option<&[u8]>) -> Header<'_> {
+    Header {
+        algorithm: Algorithm::AsconAead128 { tag_size },
+        key_id,
+        nonce: AsconAead128Nonce::from(*NONCE),
+    }
+}
+
+#[test]
+fn layout() {
+    let key = AsconAead128Key::from(*KEY);
+    let header = header(16, Some(b"key 1"));
+    let envelope = seal_with(&key, &header, PLAINTEXT, b"ad").unwrap();
+    assert_eq!(envelope.len(), header.envelope_len(PLAINTEXT.len()));
+    assert_eq!(&envelope[..4], MAGIC);
+    assert_eq!(envelope[4..7], [1, 0x1f, 5]);
+    assert_eq!(&envelope[7..12], b"key 1");
+    assert_eq!(&envelope[12..28], NONCE);
+
+    // the header is authenticated as part of the associated data
+    let mut ad = envelope[..28].to_vec();
+    ad.extend_from_slice(b"ad");
+    let mut buffer = PLAINTEXT.to_vec();
+    let tag = AsconAead128::new(&key)
+        .encrypt_inout_detached(&header.nonce, &ad, buffer.as_mut_slice().into())
+        .unwrap();
+    assert_eq!(&envelope[28..28 + PLAINTEXT.len()], buffer.as_slice());
+    assert_eq!(&envelope[28 + PLAINTEXT.len()..], tag.as_slice());
+}
+
+#[test]
+fn roundtrip() {
+    let key = AsconAead128Key::from(*KEY);
+    for tag_size in 4..=16 {
+        for key_id in [None, Some(b"key".as_slice())] {
+            let header = header(tag_size, key_id);
+            let envelope = seal_with(&key, &header, PLAINTEXT, b"ad").unwrap();
+            assert_eq!(Header::parse(&envelope).unwrap(), header);
+            assert_eq!(open(&key, &envelope, b"ad").unwrap(), PLAINTEXT);
+        }
+    }
+}
+
+#[test]
+fn in_place() {
+    let key = AsconAead128Key::from(*KEY);
+    let header = header(8, Some(b"key"));
+    let mut buffer = [0u8; 128];
+    buffer[..PLAINTEXT.len()].copy_from_slice(PLAINTEXT);
+    let len = seal_in_place(&key, &header, b"ad", &mut buffer, PLAINTEXT.len()).unwrap();
+    assert_eq!(
+        &buffer[..len],
+        seal_with(&key, &header, PLAINTEXT, b"ad").unwrap()
+    );
+
+    let (parsed, plaintext) = open_in_place(&key, &mut buffer[..len], b"ad").unwrap();
+    assert_eq!(plaintext, PLAINTEXT);
+    assert_eq!(parsed, header);
+
+    let mut small = [0u8; 32];
+    assert_eq!(
+        seal_in_place(&key, &header, b"ad", &mut small, PLAINTEXT.len()),
+        Err(EnvelopeError::BufferTooSmall)
+    );
+    assert_eq!(
+        seal_in_place(&key, &header, b"ad", &mut small, usize::MAX),
+        Err(EnvelopeError::BufferTooSmall)
+    );
+}
+
+#[test]
+fn in_place_errors() {
+    let key = AsconAead128Key::from(*KEY);
+    let mut buffer = [0u8; 512];
+    buffer[..PLAINTEXT.len()].copy_from_slice(PLAINTEXT);
+    let expected = buffer;
+
+    // the buffer is unchanged if the header is invalid
+    let long_key_id = [0u8; 256];
+    assert_eq!(
+        seal_in_place(
+            &key,
+            &header(16, Some(&long_key_id)),
+            b"ad",
+            &mut buffer,
+            PLAINTEXT.len()
+        ),
+        Err(EnvelopeError::KeyIdTooLong)
+    );
+    assert_eq!(buffer, expected);
+    assert_eq!(
+        seal_in_place(&key, &header(17, None), b"ad", &mut buffer, PLAINTEXT.len()),
+        Err(EnvelopeError::UnsupportedAlgorithm(0x10))
+    );
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+fn empty_key_id() {
+    let key = AsconAead128Key::from(*KEY);
+    let envelope = seal_with(&key, &header(16, Some(b"")), PLAINTEXT, b"").unwrap();
+    assert_eq!(
+        envelope,
+        seal_with(&key, &header(16, None), PLAINTEXT, b"").unwrap()
+    );
+    assert_eq!(Header::parse(&envelope).unwrap().key_id, None);
+}
+
+#[test]
+fn tampering() {
+    let key = AsconAead128Key::from(*KEY);
+    let envelope = seal_with(&key, &header(16, Some(b"key")), PLAINTEXT, b"ad").unwrap();
+    assert_eq!(
+        open(&key, &envelope, b"other ad"),
+        Err(EnvelopeError::Aead(Error))
+    );
+
+    // flipping bits in the key ID, nonce, ciphertext or tag is detected
+    for index in 7..envelope.len() {
+        let mut modified = envelope.clone();
+        modified[index] ^= 1;
+        assert_eq!(
+            open(&key, &modified, b"ad"),
+            Err(EnvelopeError::Aead(Error))
+        );
+    }
+
+    // changing the tag size is detected
+    let mut modified = envelope.clone();
+    modified[5] = 0x1e;
+    assert_eq!(
+        open(&key, &modified, b"ad"),
+        Err(EnvelopeError::Aead(Error))
+    );
+
+    let mut buffer = envelope.clone();
+    buffer[40] ^= 1;
+    assert!(open_in_place(&key, &mut buffer, b"ad").is_err());
+    assert!(buffer[12 + 16..envelope.len() - 16].iter().all(|b| *b == 0));
+}
+
+#[test]
+fn parse_errors() {
+    let key = AsconAead128Key::from(*KEY);
+    let envelope = seal_with(&key, &header(16, Some(b"key")), PLAINTEXT, b"").unwrap();
+
+    assert_eq!(Header::parse(b"ASC"), Err(EnvelopeError::Truncated));
+    assert_eq!(Header::parse(b"NCSA"), Err(EnvelopeError::InvalidMagic));
+    assert_eq!(
+        Header::parse(&envelope[..26]),
+        Err(EnvelopeError::Truncated)
+    );
+    // key ID, nonce and tag need to be present
+    assert_eq!(
+        Header::parse(&envelope[..7 + 3 + 16 + 15]),
+        Err(EnvelopeError::Truncated)
+    );
+    assert!(Header::parse(&envelope[..7 + 3 + 16 + 16]).is_ok());
+
+    let mut modified = envelope.clone();
+    modified[4] = 2;
+    assert_eq!(
+        open(&key, &modified, b""),
+        Err(EnvelopeError::UnsupportedVersion(2))
+    );
+    for algorithm in [0x00, 0x12, 0x20, 0xff] {
+        modified = envelope.clone();
+        modified[5] = algorithm;
+        assert_eq!(
+            open(&key, &modified, b""),
+            Err(EnvelopeError::UnsupportedAlgorithm(algorithm))
+        );
+    }
+
+    let long_key_id = [0u8; 256];
+    assert_eq!(
+        seal_with(&key, &header(16, Some(&long_key_id)), PLAINTEXT, b""),
+        Err(EnvelopeError::KeyIdTooLong)
+    );
+    assert_eq!(
+        seal_with(&key, &header(3, None), PLAINTEXT, b""),
+        Err(EnvelopeError::UnsupportedAlgorithm(0x12))
+    );
+}
+
+/// Random number generator returning a fixed nonce
+#[cfg(feature = "rand_core")]
+struct FixedRng(&'static [u8; 16]);
+
+#[cfg(feature = "rand_core")]
+impl ascon_aead::aead::rand_core::TryRng for FixedRng {
+    type Error = core::convert::Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        unimplemented!()
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        unimplemented!()
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        dst.copy_from_slice(self.0);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl ascon_aead::aead::rand_core::TryCryptoRng for FixedRng {}
+
+#[cfg(feature = "rand_core")]
+#[test]
+fn seal_with_rng() {
+    let key = AsconAead128Key::from(*KEY);
+    let envelope =
+        ascon_aead::envelope::seal_with_rng(&key, &mut FixedRng(NONCE), PLAINTEXT, b"ad").unwrap();
+    assert_eq!(
+        envelope,
+        seal_with(&key, &header(16, None), PLAINTEXT, b"ad").unwrap()
+    );
+    assert_eq!(open(&key, &envelope, b"ad").unwrap(), PLAINTEXT);
+}
@@ -0,0 +1,87 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use ascon_aead::{
+    AsconAead128, AsconAead128Decryptor, AsconAead128Encryptor, AsconAead128Key, AsconAead128Nonce,
+    AsconAead128Tag,
+    aead::{AeadInOut, KeyInit},
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE: &[u8; 16] = b"unique nonce 012";
+
+fn data(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+fn run_chunked(associated_data: &[u8], plaintext: &[u8], chunk_size: usize) {
+    let key = AsconAead128Key::from(*KEY);
+    let nonce = AsconAead128Nonce::from(*NONCE);
+
+    let mut expected = plaintext.to_vec();
+    let expected_tag = AsconAead128::new(&key)
+        .encrypt_inout_detached(&nonce, associated_data, expected.as_mut_slice().into())
+        .unwrap();
+
+    let mut encryptor = AsconAead128Encryptor::new(&key, &nonce);
+    for chunk in associated_data.chunks(chunk_size) {
+        encryptor.update_associated_data(chunk).unwrap();
+    }
+    let mut buffer = plaintext.to_vec();
+    for chunk in buffer.chunks_mut(chunk_size) {
+        encryptor.encrypt_in_place(chunk);
+    }
+    let tag = encryptor.finalize();
+    assert_eq!(buffer, expected);
+    assert_eq!(tag, expected_tag);
+
+    let mut decryptor = AsconAead128Decryptor::new(&key, &nonce);
+    for chunk in associated_data.chunks(chunk_size) {
+        decryptor.update_associated_data(chunk).unwrap();
+    }
+    for chunk in buffer.chunks_mut(chunk_size) {
+        decryptor.decrypt_in_place_unverified(chunk);
+    }
+    assert!(decryptor.verify(&tag).is_ok());
+    assert_eq!(buffer, plaintext);
+}
+
+#[test]
+fn incremental_matches_one_shot() {
+    for ad_len in 0..=40 {
+        for msg_len in 0..=40 {
+            for chunk_size in 1..=17 {
+                run_chunked(&data(ad_len), &data(msg_len), chunk_size);
+            }
+        }
+    }
+}
+
+#[test]
+fn incremental_bad_tag() {
+    let key = AsconAead128Key::from(*KEY);
+    let nonce = AsconAead128Nonce::from(*NONCE);
+
+    let mut buffer = data(20);
+    let mut encryptor = AsconAead128Encryptor::new(&key, &nonce);
+    encryptor.encrypt_in_place(&mut buffer);
+    let tag = encryptor.finalize();
+
+    let mut decryptor = AsconAead128Decryptor::new(&key, &nonce);
+    decryptor.decrypt_in_place_unverified(&mut buffer);
+    assert!(decryptor.verify(&AsconAead128Tag::default()).is_err());
+    assert_ne!(tag, AsconAead128Tag::default());
+}
+
+#[test]
+fn incremental_associated_data_after_message() {
+    let key = AsconAead128Key::from(*KEY);
+    let nonce = AsconAead128Nonce::from(*NONCE);
+
+    let mut encryptor = AsconAead128Encryptor::new(&key, &nonce);
+    encryptor
+        .update_associated_data(b"associated data")
+        .unwrap();
+    encryptor.encrypt_in_place(&mut [0u8; 3]);
+    assert!(encryptor.update_associated_data(b"more data").is_err());
+}
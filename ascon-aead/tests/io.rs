@@ -0,0 +1,247 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "std")]
+
+use std::io::{self, Read, Write};
+
+use ascon_aead::{
+    AsconAead128Key,
+    io::{DecryptingReader, EncryptingWriter, HEADER_SIZE, MAX_CHUNK_SIZE},
+    stream::StreamNoncePrefix,
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE_PREFIX: &[u8; 11] = b"unique 0123";
+const CHUNK_SIZE: usize = 32;
+const ENCRYPTED_CHUNK_SIZE: usize = CHUNK_SIZE + 16;
+
+fn plaintext(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+fn encrypt(plaintext: &[u8]) -> Vec<u8> {
+    let mut writer = EncryptingWriter::with_chunk_size(
+        Vec::new(),
+        &AsconAead128Key::from(*KEY),
+        &StreamNoncePrefix::from(*NONCE_PREFIX),
+        CHUNK_SIZE,
+    )
+    .unwrap();
+    writer.write_all(plaintext).unwrap();
+    writer.finish().unwrap()
+}
+
+fn decrypt(ciphertext: &[u8]) -> io::Result<Vec<u8>> {
+    let mut reader = DecryptingReader::new(ciphertext, &AsconAead128Key::from(*KEY));
+    let mut plaintext = Vec::new();
+    reader.read_to_end(&mut plaintext)?;
+    Ok(plaintext)
+}
+
+/// Reader returning at most one byte per call
+struct ByteReader<'a>(&'a [u8]);
+
+impl Read for ByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match (self.0.split_first(), buf.first_mut()) {
+            (Some((byte, rest)), Some(out)) => {
+                *out = *byte;
+                self.0 = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+/// Reader failing once after the given number of bytes
+struct FailingReader<'a> {
+    data: &'a [u8],
+    fail_at: usize,
+}
+
+impl Read for FailingReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.fail_at == 0 {
+            self.fail_at = usize::MAX;
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+        let len = buf.len().min(self.fail_at).min(self.data.len());
+        buf[..len].copy_from_slice(&self.data[..len]);
+        self.data = &self.data[len..];
+        self.fail_at -= len;
+        Ok(len)
+    }
+}
+
+#[test]
+fn roundtrip() {
+    for len in [
+        0,
+        1,
+        CHUNK_SIZE - 1,
+        CHUNK_SIZE,
+        CHUNK_SIZE + 1,
+        3 * CHUNK_SIZE,
+        5 * CHUNK_SIZE + 7,
+    ] {
+        let plaintext = plaintext(len);
+        let ciphertext = encrypt(&plaintext);
+        let chunks = len.div_ceil(CHUNK_SIZE).max(1);
+        assert_eq!(ciphertext.len(), HEADER_SIZE + len + 16 * chunks);
+        assert_eq!(decrypt(&ciphertext).unwrap(), plaintext);
+    }
+}
+
+#[test]
+fn small_writes_and_reads() {
+    let plaintext = plaintext(3 * CHUNK_SIZE + 5);
+    let mut writer = EncryptingWriter::with_chunk_size(
+        Vec::new(),
+        &AsconAead128Key::from(*KEY),
+        &StreamNoncePrefix::from(*NONCE_PREFIX),
+        CHUNK_SIZE,
+    )
+    .unwrap();
+    for byte in &plaintext {
+        writer.write_all(&[*byte]).unwrap();
+    }
+    let ciphertext = writer.finish().unwrap();
+    assert_eq!(ciphertext, encrypt(&plaintext));
+
+    let mut reader = DecryptingReader::new(ByteReader(&ciphertext), &AsconAead128Key::from(*KEY));
+    let mut decrypted = Vec::new();
+    let mut buffer = [0u8; 3];
+    loop {
+        let len = reader.read(&mut buffer).unwrap();
+        if len == 0 {
+            break;
+        }
+        decrypted.extend_from_slice(&buffer[..len]);
+    }
+    assert_eq!(decrypted, plaintext);
+}
+
+#[test]
+fn copy() {
+    let plaintext = plaintext(1000);
+    let mut writer = EncryptingWriter::new(
+        Vec::new(),
+        &AsconAead128Key::from(*KEY),
+        &StreamNoncePrefix::from(*NONCE_PREFIX),
+    );
+    io::copy(&mut plaintext.as_slice(), &mut writer).unwrap();
+    let ciphertext = writer.finish().unwrap();
+
+    let mut reader = DecryptingReader::new(ciphertext.as_slice(), &AsconAead128Key::from(*KEY));
+    let mut decrypted = Vec::new();
+    io::copy(&mut reader, &mut decrypted).unwrap();
+    assert_eq!(decrypted, plaintext);
+}
+
+#[test]
+fn truncation() {
+    let ciphertext = encrypt(&plaintext(3 * CHUNK_SIZE));
+    // truncate at chunk boundaries, within the header and within a chunk
+    for len in [
+        0,
+        HEADER_SIZE - 1,
+        HEADER_SIZE,
+        HEADER_SIZE + 10,
+        HEADER_SIZE + ENCRYPTED_CHUNK_SIZE,
+        HEADER_SIZE + 2 * ENCRYPTED_CHUNK_SIZE,
+        ciphertext.len() - 1,
+    ] {
+        let err = decrypt(&ciphertext[..len]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    // appending data is detected as well
+    let mut extended = ciphertext.clone();
+    extended.push(0);
+    assert!(decrypt(&extended).is_err());
+}
+
+#[test]
+fn reordering() {
+    let ciphertext = encrypt(&plaintext(3 * CHUNK_SIZE));
+    let first = HEADER_SIZE..HEADER_SIZE + ENCRYPTED_CHUNK_SIZE;
+    let second = first.end..first.end + ENCRYPTED_CHUNK_SIZE;
+
+    let mut reordered = ciphertext[..HEADER_SIZE].to_vec();
+    reordered.extend_from_slice(&ciphertext[second.clone()]);
+    reordered.extend_from_slice(&ciphertext[first.clone()]);
+    reordered.extend_from_slice(&ciphertext[second.end..]);
+    assert!(decrypt(&reordered).is_err());
+
+    // no plaintext is returned from the out-of-order chunk
+    let mut reader = DecryptingReader::new(reordered.as_slice(), &AsconAead128Key::from(*KEY));
+    let mut buffer = [0u8; 1];
+    assert!(reader.read(&mut buffer).is_err());
+    assert!(reader.read(&mut buffer).is_err());
+
+    let mut duplicated = ciphertext[..second.end].to_vec();
+    duplicated.extend_from_slice(&ciphertext[second.clone()]);
+    duplicated.extend_from_slice(&ciphertext[second.end..]);
+    assert!(decrypt(&duplicated).is_err());
+}
+
+#[test]
+fn tampering() {
+    let ciphertext = encrypt(&plaintext(2 * CHUNK_SIZE + 3));
+    for index in 0..ciphertext.len() {
+        let mut modified = ciphertext.clone();
+        modified[index] ^= 1;
+        assert!(decrypt(&modified).is_err(), "index {index}");
+    }
+
+    // the first chunk is still returned if the second chunk is modified
+    let mut modified = ciphertext.clone();
+    modified[HEADER_SIZE + ENCRYPTED_CHUNK_SIZE] ^= 1;
+    let mut reader = DecryptingReader::new(modified.as_slice(), &AsconAead128Key::from(*KEY));
+    let mut buffer = [0u8; 2 * CHUNK_SIZE];
+    assert_eq!(reader.read(&mut buffer).unwrap(), CHUNK_SIZE);
+    assert!(reader.read(&mut buffer).is_err());
+}
+
+#[test]
+fn invalid_chunk_size() {
+    let key = AsconAead128Key::from(*KEY);
+    let nonce_prefix = StreamNoncePrefix::from(*NONCE_PREFIX);
+    for chunk_size in [0, MAX_CHUNK_SIZE + 1] {
+        let err = EncryptingWriter::with_chunk_size(Vec::new(), &key, &nonce_prefix, chunk_size)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    let mut ciphertext = encrypt(b"");
+    ciphertext[1..5].copy_from_slice(&u32::MAX.to_be_bytes());
+    assert_eq!(
+        decrypt(&ciphertext).unwrap_err().kind(),
+        io::ErrorKind::InvalidData
+    );
+}
+
+#[test]
+fn inner_error() {
+    let plaintext = plaintext(3 * CHUNK_SIZE);
+    let ciphertext = encrypt(&plaintext);
+    // fail in the middle of the second chunk
+    let inner = FailingReader {
+        data: &ciphertext,
+        fail_at: HEADER_SIZE + ENCRYPTED_CHUNK_SIZE + 1 + CHUNK_SIZE / 2,
+    };
+    let mut reader = DecryptingReader::new(inner, &AsconAead128Key::from(*KEY));
+    let mut buffer = [0u8; CHUNK_SIZE];
+    reader.read_exact(&mut buffer).unwrap();
+    assert_eq!(buffer.as_slice(), &plaintext[..CHUNK_SIZE]);
+    assert_eq!(
+        reader.read(&mut buffer).unwrap_err().kind(),
+        io::ErrorKind::TimedOut
+    );
+    // the partially read ciphertext is never returned
+    assert!(reader.read(&mut buffer).is_err());
+    assert!(reader.read(&mut []).is_err());
+}
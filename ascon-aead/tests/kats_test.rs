@@ -4,7 +4,7 @@
 use std::{collections::HashMap, include_str};
 
 use ascon_aead::{
-    AsconAead128, Key, Nonce, Tag,
+    AsconAead128, AsconAead128NonceMasked, Key, Nonce, Tag,
     aead::{Aead, AeadInOut, KeyInit, Payload},
 };
 use spectral::prelude::{OrderedAssertions, ResultAssertions, asserting};
@@ -81,6 +81,9 @@ fn parse_tvs(tvs: &str) -> Vec<TestVector> {
     let mut ret = Vec::new();
 
     for line in tvs.lines() {
+        if line.starts_with('#') || (line.is_empty() && fields.is_empty()) {
+            continue;
+        }
         if line.is_empty() && !fields.is_empty() {
             ret.push(TestVector::new(
                 fields["Count"],
@@ -111,3 +114,49 @@ fn test_vectors_ascon128() {
         run_tv::<AsconAead128>(tv);
     }
 }
+
+#[test]
+fn test_vectors_ascon128_nonce_masked() {
+    let tvs = parse_tvs(include_str!("data/ascon128nm.txt"));
+    for tv in tvs {
+        run_tv::<AsconAead128NonceMasked>(tv);
+    }
+}
+
+#[test]
+fn test_vectors_ascon128_nonce_masked_derived() {
+    let tvs = parse_tvs(include_str!("data/ascon128nm.txt"));
+    for mut tv in tvs {
+        // Ascon-AEAD128 with the first half of the key and the nonce masked with the second half
+        let mask = tv.key.split_off(16);
+        tv.nonce.iter_mut().zip(mask).for_each(|(n, m)| *n ^= m);
+        run_tv::<AsconAead128>(tv);
+    }
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn test_vectors_ascon128v12() {
+    let tvs = parse_tvs(include_str!("data/ascon128v12.txt"));
+    for tv in tvs {
+        run_tv::<ascon_aead::Ascon128>(tv);
+    }
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn test_vectors_ascon128av12() {
+    let tvs = parse_tvs(include_str!("data/ascon128av12.txt"));
+    for tv in tvs {
+        run_tv::<ascon_aead::Ascon128a>(tv);
+    }
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn test_vectors_ascon80pqv12() {
+    let tvs = parse_tvs(include_str!("data/ascon80pqv12.txt"));
+    for tv in tvs {
+        run_tv::<ascon_aead::Ascon80pq>(tv);
+    }
+}
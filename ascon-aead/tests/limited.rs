@@ -0,0 +1,129 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use ascon_aead::{
+    AsconAead128, AsconAead128Key, AsconAead128Nonce, Error, LimitedAsconAead128, UsageCounters,
+    UsageError, UsageLimits,
+    aead::{AeadInOut, KeyInit},
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE: &[u8; 16] = b"unique nonce 012";
+const LIMITS: UsageLimits = UsageLimits {
+    max_messages: 4,
+    max_bytes: 64,
+    max_failed_decryptions: 2,
+};
+
+#[test]
+fn matches_ascon_aead128() {
+    let key = AsconAead128Key::from(*KEY);
+    let nonce = AsconAead128Nonce::from(*NONCE);
+    let mut cipher = LimitedAsconAead128::new(&key, LIMITS);
+
+    let mut buffer = *b"plaintext message";
+    let tag = cipher
+        .encrypt_in_place_detached(&nonce, b"ad", &mut buffer)
+        .unwrap();
+    let mut expected = *b"plaintext message";
+    let expected_tag = AsconAead128::new(&key)
+        .encrypt_inout_detached(&nonce, b"ad", expected.as_mut_slice().into())
+        .unwrap();
+    assert_eq!(buffer, expected);
+    assert_eq!(tag, expected_tag);
+
+    cipher
+        .decrypt_in_place_detached(&nonce, b"ad", &mut buffer, &tag)
+        .unwrap();
+    assert_eq!(&buffer, b"plaintext message");
+    assert_eq!(
+        cipher.counters(),
+        UsageCounters {
+            messages: 2,
+            bytes: 38,
+            failed_decryptions: 0
+        }
+    );
+}
+
+#[test]
+fn message_limit() {
+    let mut cipher = LimitedAsconAead128::new(&AsconAead128Key::from(*KEY), LIMITS);
+    let nonce = AsconAead128Nonce::from(*NONCE);
+    for _ in 0..4 {
+        cipher
+            .encrypt_in_place_detached(&nonce, b"", &mut [0u8; 1])
+            .unwrap();
+    }
+    assert_eq!(
+        cipher.encrypt_in_place_detached(&nonce, b"", &mut [0u8; 1]),
+        Err(UsageError::MessageLimitReached)
+    );
+    assert_eq!(cipher.counters().messages, 4);
+}
+
+#[test]
+fn byte_limit() {
+    let mut cipher = LimitedAsconAead128::new(&AsconAead128Key::from(*KEY), LIMITS);
+    let nonce = AsconAead128Nonce::from(*NONCE);
+    cipher
+        .encrypt_in_place_detached(&nonce, &[0u8; 32], &mut [0u8; 16])
+        .unwrap();
+    let mut buffer = [0xaa; 17];
+    assert_eq!(
+        cipher.encrypt_in_place_detached(&nonce, b"", &mut buffer),
+        Err(UsageError::ByteLimitReached)
+    );
+    // the buffer is not processed
+    assert_eq!(buffer, [0xaa; 17]);
+    cipher
+        .encrypt_in_place_detached(&nonce, b"", &mut [0u8; 16])
+        .unwrap();
+    assert_eq!(cipher.counters().bytes, 64);
+}
+
+#[test]
+fn failed_decryption_limit() {
+    let mut cipher = LimitedAsconAead128::new(&AsconAead128Key::from(*KEY), LIMITS);
+    let nonce = AsconAead128Nonce::from(*NONCE);
+    let tag = Default::default();
+    for _ in 0..2 {
+        assert_eq!(
+            cipher.decrypt_in_place_detached(&nonce, b"", &mut [0u8; 1], &tag),
+            Err(UsageError::Aead(Error))
+        );
+    }
+    assert_eq!(
+        cipher.decrypt_in_place_detached(&nonce, b"", &mut [0u8; 1], &tag),
+        Err(UsageError::FailedDecryptionLimitReached)
+    );
+    assert_eq!(
+        cipher.encrypt_in_place_detached(&nonce, b"", &mut [0u8; 1]),
+        Err(UsageError::FailedDecryptionLimitReached)
+    );
+}
+
+#[test]
+fn persist_counters() {
+    let key = AsconAead128Key::from(*KEY);
+    let nonce = AsconAead128Nonce::from(*NONCE);
+    let mut cipher = LimitedAsconAead128::new(&key, LIMITS);
+    for _ in 0..3 {
+        cipher
+            .encrypt_in_place_detached(&nonce, b"", &mut [0u8; 5])
+            .unwrap();
+    }
+    let bytes = cipher.counters().to_bytes();
+    assert_eq!(UsageCounters::from_bytes(&bytes), cipher.counters());
+
+    let mut cipher =
+        LimitedAsconAead128::with_counters(&key, LIMITS, UsageCounters::from_bytes(&bytes));
+    assert_eq!(cipher.limits(), LIMITS);
+    cipher
+        .encrypt_in_place_detached(&nonce, b"", &mut [0u8; 5])
+        .unwrap();
+    assert_eq!(
+        cipher.encrypt_in_place_detached(&nonce, b"", &mut [0u8; 5]),
+        Err(UsageError::MessageLimitReached)
+    );
+}
@@ -0,0 +1,91 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::HashSet;
+
+use ascon_aead::{
+    AsconAead128Nonce,
+    aead::{
+        array::Array,
+        consts::{U8, U14, U15},
+    },
+    nonce::{CounterNonceSequence, NonceSequence, XorNonceSequence},
+};
+
+#[test]
+fn counter_sequence() {
+    let mut nonces = CounterNonceSequence::<U8>::new(&Array(*b"prefix 0"));
+    assert_eq!(nonces.counter(), Some(0));
+    assert_eq!(
+        nonces.next_nonce().unwrap(),
+        AsconAead128Nonce::from(*b"prefix 0\0\0\0\0\0\0\0\0")
+    );
+    assert_eq!(
+        nonces.next_nonce().unwrap(),
+        AsconAead128Nonce::from(*b"prefix 0\0\0\0\0\0\0\0\x01")
+    );
+    assert_eq!(nonces.counter(), Some(2));
+
+    let mut nonces =
+        CounterNonceSequence::<U8>::with_counter(&Array(*b"prefix 0"), 0x0102).unwrap();
+    assert_eq!(
+        nonces.next_nonce().unwrap(),
+        AsconAead128Nonce::from(*b"prefix 0\0\0\0\0\0\0\x01\x02")
+    );
+}
+
+#[test]
+fn counter_sequence_exhausted() {
+    let mut nonces = CounterNonceSequence::<U15>::new(&Array(*b"15 byte prefix."));
+    let mut seen = HashSet::new();
+    for _ in 0..256 {
+        assert!(seen.insert(nonces.next_nonce().unwrap()));
+    }
+    assert_eq!(nonces.counter(), None);
+    assert!(nonces.next_nonce().is_err());
+    assert!(nonces.next_nonce().is_err());
+
+    let prefix = Array(*b"14 byte prefix");
+    assert!(CounterNonceSequence::<U14>::with_counter(&prefix, 0x10000).is_err());
+    let mut nonces = CounterNonceSequence::<U14>::with_counter(&prefix, 0xffff).unwrap();
+    assert_eq!(nonces.next_nonce().unwrap()[14..], [0xff, 0xff]);
+    assert!(nonces.next_nonce().is_err());
+}
+
+#[test]
+fn xor_sequence() {
+    let iv = AsconAead128Nonce::from([0xaa; 16]);
+    let mut nonces = XorNonceSequence::new(&iv);
+    assert_eq!(nonces.next_nonce().unwrap(), iv);
+    let mut expected = [0xaa; 16];
+    expected[15] ^= 1;
+    assert_eq!(
+        nonces.next_nonce().unwrap(),
+        AsconAead128Nonce::from(expected)
+    );
+    assert_eq!(nonces.sequence_number(), Some(2));
+
+    let mut expected = [0xaa; 16];
+    expected[8..].copy_from_slice(&(0x0102030405060708u64 ^ 0xaaaaaaaaaaaaaaaa).to_be_bytes());
+    assert_eq!(
+        nonces.nonce(0x0102030405060708),
+        AsconAead128Nonce::from(expected)
+    );
+
+    let mut nonces = XorNonceSequence::with_sequence_number(&iv, u64::MAX);
+    assert_eq!(nonces.next_nonce().unwrap(), nonces.nonce(u64::MAX));
+    assert_eq!(nonces.sequence_number(), None);
+    assert!(nonces.next_nonce().is_err());
+}
+
+#[cfg(feature = "getrandom")]
+#[test]
+fn random_generator() {
+    use ascon_aead::nonce::RandomNonceGenerator;
+
+    let mut nonces = RandomNonceGenerator::default();
+    let mut seen = HashSet::new();
+    for _ in 0..100 {
+        assert!(seen.insert(nonces.next_nonce().unwrap()));
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "rayon")]
+
+use ascon_aead::{
+    AsconAead128Key, AsconAead128Nonce, Error,
+    parallel::{MANIFEST_SIZE, par_open, par_seal, par_seal_with_segment_size},
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE: &[u8; 16] = b"unique nonce 012";
+const SEGMENT_SIZE: usize = 64;
+const ENCRYPTED_SEGMENT_SIZE: usize = SEGMENT_SIZE + 16;
+
+fn plaintext(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+fn seal(plaintext: &[u8]) -> Vec<u8> {
+    par_seal_with_segment_size(
+        &AsconAead128Key::from(*KEY),
+        &AsconAead128Nonce::from(*NONCE),
+        b"ad",
+        plaintext,
+        SEGMENT_SIZE,
+    )
+    .unwrap()
+}
+
+fn open(ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+    par_open(
+        &AsconAead128Key::from(*KEY),
+        &AsconAead128Nonce::from(*NONCE),
+        b"ad",
+        ciphertext,
+    )
+}
+
+#[test]
+fn roundtrip() {
+    for len in [
+        0,
+        1,
+        SEGMENT_SIZE,
+        SEGMENT_SIZE + 1,
+        10 * SEGMENT_SIZE,
+        37 * SEGMENT_SIZE + 5,
+    ] {
+        let plaintext = plaintext(len);
+        let ciphertext = seal(&plaintext);
+        assert_eq!(
+            ciphertext.len(),
+            len + 16 * len.div_ceil(SEGMENT_SIZE) + MANIFEST_SIZE
+        );
+        assert_eq!(open(&ciphertext).unwrap(), plaintext);
+    }
+}
+
+#[test]
+fn default_segment_size() {
+    let key = AsconAead128Key::from(*KEY);
+    let nonce = AsconAead128Nonce::from(*NONCE);
+    let plaintext = plaintext(3 << 20);
+    let ciphertext = par_seal(&key, &nonce, b"", &plaintext).unwrap();
+    assert_eq!(par_open(&key, &nonce, b"", &ciphertext).unwrap(), plaintext);
+    assert!(par_open(&key, &nonce, b"ad", &ciphertext).is_err());
+}
+
+#[test]
+fn deterministic() {
+    let plaintext = plaintext(10 * SEGMENT_SIZE);
+    assert_eq!(seal(&plaintext), seal(&plaintext));
+    assert_ne!(
+        seal(&plaintext),
+        par_seal_with_segment_size(
+            &AsconAead128Key::from(*KEY),
+            &AsconAead128Nonce::from(*b"unique nonce 013"),
+            b"ad",
+            &plaintext,
+            SEGMENT_SIZE,
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn rejects_modified_segments() {
+    let ciphertext = seal(&plaintext(4 * SEGMENT_SIZE + 3));
+    let segment = |index: usize| {
+        let start = index * ENCRYPTED_SEGMENT_SIZE;
+        &ciphertext[start..(start + ENCRYPTED_SEGMENT_SIZE).min(ciphertext.len() - MANIFEST_SIZE)]
+    };
+    let manifest = &ciphertext[ciphertext.len() - MANIFEST_SIZE..];
+
+    // reordered
+    let reordered = [
+        segment(1),
+        segment(0),
+        segment(2),
+        segment(3),
+        segment(4),
+        manifest,
+    ]
+    .concat();
+    assert_eq!(open(&reordered), Err(Error));
+
+    // duplicated
+    let duplicated = [
+        segment(0),
+        segment(0),
+        segment(2),
+        segment(3),
+        segment(4),
+        manifest,
+    ]
+    .concat();
+    assert_eq!(open(&duplicated), Err(Error));
+
+    // missing
+    let missing = [segment(0), segment(2), segment(3), segment(4), manifest].concat();
+    assert_eq!(open(&missing), Err(Error));
+
+    // truncated
+    let truncated = [segment(0), segment(1), segment(2), segment(3), manifest].concat();
+    assert_eq!(open(&truncated), Err(Error));
+
+    for index in 0..ciphertext.len() {
+        let mut modified = ciphertext.clone();
+        modified[index] ^= 1;
+        assert_eq!(open(&modified), Err(Error), "index {index}");
+    }
+}
+
+#[test]
+fn rejects_swapped_nonces() {
+    let key = AsconAead128Key::from(*KEY);
+    let nonces = [
+        AsconAead128Nonce::from(*NONCE),
+        AsconAead128Nonce::from(*b"unique nonce 013"),
+    ];
+    let seal = |nonce, len| {
+        par_seal_with_segment_size(&key, nonce, b"", &plaintext(len), SEGMENT_SIZE).unwrap()
+    };
+
+    // empty and single segment ciphertexts
+    for len in [0, SEGMENT_SIZE / 2] {
+        let first = seal(&nonces[0], len);
+        let second = seal(&nonces[1], len);
+        assert!(par_open(&key, &nonces[0], b"", &first).is_ok());
+        assert_eq!(par_open(&key, &nonces[1], b"", &first), Err(Error));
+        assert_eq!(par_open(&key, &nonces[0], b"", &second), Err(Error));
+
+        // segment of one message with the manifest of the other
+        if len > 0 {
+            let (segment, manifest) = first.split_at(first.len() - MANIFEST_SIZE);
+            let mixed = [&second[..segment.len()], manifest].concat();
+            assert_eq!(par_open(&key, &nonces[0], b"", &mixed), Err(Error));
+            let mixed = [segment, &second[segment.len()..]].concat();
+            assert_eq!(par_open(&key, &nonces[1], b"", &mixed), Err(Error));
+        }
+    }
+}
+
+#[test]
+fn rejects_invalid_manifest() {
+    let ciphertext = seal(&plaintext(4 * SEGMENT_SIZE));
+    let manifest = ciphertext.len() - MANIFEST_SIZE;
+
+    // changing the segment size or count without matching lengths
+    for (offset, value) in [(0, 0), (0, u64::MAX), (8, 3), (16, 0)] {
+        let mut modified = ciphertext.clone();
+        modified[manifest + offset..manifest + offset + 8]
+            .copy_from_slice(&u64::to_be_bytes(value));
+        assert_eq!(open(&modified), Err(Error));
+    }
+
+    assert_eq!(open(&ciphertext[..MANIFEST_SIZE - 1]), Err(Error));
+    assert_eq!(
+        par_seal_with_segment_size(
+            &AsconAead128Key::from(*KEY),
+            &AsconAead128Nonce::from(*NONCE),
+            b"",
+            b"",
+            0
+        ),
+        Err(Error)
+    );
+}
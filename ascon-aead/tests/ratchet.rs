@@ -0,0 +1,150 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "ratchet")]
+
+use ascon_aead::{
+    AsconAead128, AsconAead128Key, AsconAead128Tag, RatchetPolicy, RatchetingAsconAead128,
+    aead::{AeadInOut, KeyInit},
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE: &[u8; 16] = b"unique nonce 012";
+
+fn encrypt(
+    sender: &mut RatchetingAsconAead128<2>,
+    message: &[u8],
+) -> (u64, Vec<u8>, AsconAead128Tag) {
+    let mut buffer = message.to_vec();
+    let (epoch, tag) = sender
+        .encrypt_in_place_detached(NONCE.into(), b"", &mut buffer)
+        .unwrap();
+    (epoch, buffer, tag)
+}
+
+#[test]
+fn first_epoch_matches_ascon_aead128() {
+    let key = AsconAead128Key::from(*KEY);
+    let mut sender = RatchetingAsconAead128::<2>::new(&key, RatchetPolicy::default());
+    let (epoch, ciphertext, tag) = encrypt(&mut sender, b"message");
+    assert_eq!(epoch, 0);
+
+    let mut expected = *b"message";
+    let expected_tag = AsconAead128::new(&key)
+        .encrypt_inout_detached(NONCE.into(), b"", expected.as_mut_slice().into())
+        .unwrap();
+    assert_eq!(ciphertext, expected);
+    assert_eq!(tag, expected_tag);
+
+    sender.ratchet().unwrap();
+    let (epoch, ciphertext, _) = encrypt(&mut sender, b"message");
+    assert_eq!(epoch, 1);
+    assert_ne!(ciphertext, expected);
+}
+
+#[test]
+fn policy() {
+    let key = AsconAead128Key::from(*KEY);
+    let policy = RatchetPolicy {
+        max_messages: 3,
+        max_bytes: 20,
+    };
+    let mut sender = RatchetingAsconAead128::<2>::new(&key, policy);
+    let epochs: Vec<_> = (0..7).map(|_| encrypt(&mut sender, b"12345").0).collect();
+    assert_eq!(epochs, [0, 0, 0, 1, 1, 1, 2]);
+
+    let mut sender = RatchetingAsconAead128::<2>::new(&key, policy);
+    let epochs: Vec<_> = (0..4)
+        .map(|_| encrypt(&mut sender, b"0123456789").0)
+        .collect();
+    assert_eq!(epochs, [0, 0, 1, 1]);
+    // messages exceeding the limit on their own still get encrypted
+    assert_eq!(encrypt(&mut sender, &[0u8; 32]).0, 2);
+    assert_eq!(encrypt(&mut sender, b"").0, 3);
+}
+
+#[test]
+fn out_of_order() {
+    let key = AsconAead128Key::from(*KEY);
+    let policy = RatchetPolicy {
+        max_messages: 1,
+        ..Default::default()
+    };
+    let mut sender = RatchetingAsconAead128::<2>::new(&key, policy);
+    let mut receiver = RatchetingAsconAead128::<2>::new(&key, RatchetPolicy::default());
+    let messages: Vec<_> = (0..9u8).map(|i| encrypt(&mut sender, &[i; 8])).collect();
+
+    let decrypt = |receiver: &mut RatchetingAsconAead128<2>, i: usize| {
+        let (epoch, ciphertext, tag) = &messages[i];
+        let mut buffer = ciphertext.clone();
+        receiver
+            .decrypt_in_place_detached(*epoch, NONCE.into(), b"", &mut buffer, tag)
+            .map(|_| assert_eq!(buffer, [i as u8; 8]))
+    };
+
+    // skip two epochs
+    decrypt(&mut receiver, 3).unwrap();
+    assert_eq!(receiver.epoch(), 3);
+    decrypt(&mut receiver, 1).unwrap();
+    decrypt(&mut receiver, 2).unwrap();
+    decrypt(&mut receiver, 3).unwrap();
+    // only the keys of the last two epochs are retained
+    assert!(decrypt(&mut receiver, 0).is_err());
+    // skipping three epochs is not possible
+    assert!(decrypt(&mut receiver, 7).is_err());
+    assert_eq!(receiver.epoch(), 3);
+    // skip one epoch
+    decrypt(&mut receiver, 5).unwrap();
+    assert_eq!(receiver.epoch(), 5);
+    assert!(decrypt(&mut receiver, 1).is_err());
+    assert!(decrypt(&mut receiver, 2).is_err());
+    decrypt(&mut receiver, 4).unwrap();
+    decrypt(&mut receiver, 3).unwrap();
+    receiver.forget_previous_epochs();
+    assert!(decrypt(&mut receiver, 4).is_err());
+    decrypt(&mut receiver, 8).unwrap();
+}
+
+#[test]
+fn in_order_without_skipping() {
+    let key = AsconAead128Key::from(*KEY);
+    let policy = RatchetPolicy {
+        max_messages: 1,
+        ..Default::default()
+    };
+    let mut sender = RatchetingAsconAead128::<2>::new(&key, policy);
+    let mut receiver = RatchetingAsconAead128::<0>::new(&key, RatchetPolicy::default());
+    let messages: Vec<_> = (0..4u8).map(|i| encrypt(&mut sender, &[i; 8])).collect();
+
+    let decrypt = |receiver: &mut RatchetingAsconAead128<0>, i: usize| {
+        let (epoch, ciphertext, tag) = &messages[i];
+        let mut buffer = ciphertext.clone();
+        receiver
+            .decrypt_in_place_detached(*epoch, NONCE.into(), b"", &mut buffer, tag)
+            .map(|_| assert_eq!(buffer, [i as u8; 8]))
+    };
+
+    decrypt(&mut receiver, 0).unwrap();
+    decrypt(&mut receiver, 1).unwrap();
+    assert_eq!(receiver.epoch(), 1);
+    // no keys of previous epochs are retained
+    assert!(decrypt(&mut receiver, 0).is_err());
+    // skipping an epoch is not possible
+    assert!(decrypt(&mut receiver, 3).is_err());
+    decrypt(&mut receiver, 2).unwrap();
+    decrypt(&mut receiver, 3).unwrap();
+    assert_eq!(receiver.epoch(), 3);
+}
+
+#[test]
+fn forged_message_does_not_advance() {
+    let key = AsconAead128Key::from(*KEY);
+    let mut receiver = RatchetingAsconAead128::<2>::new(&key, RatchetPolicy::default());
+    let mut buffer = *b"message";
+    assert!(
+        receiver
+            .decrypt_in_place_detached(2, NONCE.into(), b"", &mut buffer, &Default::default())
+            .is_err()
+    );
+    assert_eq!(receiver.epoch(), 0);
+}
@@ -0,0 +1,126 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(all(feature = "siv", feature = "alloc"))]
+
+use ascon_aead::{
+    AsconSiv, AsconSivKey, AsconSivNonce,
+    aead::{
+        Aead, AeadInOut, KeyInit, Payload,
+        consts::{U0, U16},
+    },
+};
+
+const KEY: &[u8; 32] = b"a very secret key of 256 bits!!!";
+
+#[test]
+fn roundtrip() {
+    let cipher = AsconSiv::<U0>::new(&AsconSivKey::from(*KEY));
+    let nonce = AsconSivNonce::<U0>::default();
+    for len in 0..=40 {
+        let msg: Vec<u8> = (0..len).collect();
+        let payload = Payload {
+            msg: &msg,
+            aad: b"associated data",
+        };
+        let ciphertext = cipher.encrypt(&nonce, payload).unwrap();
+        assert_eq!(ciphertext.len(), len as usize + 16);
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: b"associated data",
+        };
+        assert_eq!(cipher.decrypt(&nonce, payload).unwrap(), msg);
+    }
+}
+
+#[test]
+fn deterministic() {
+    let cipher = AsconSiv::<U0>::new(&AsconSivKey::from(*KEY));
+    let nonce = AsconSivNonce::<U0>::default();
+    let first = cipher.encrypt(&nonce, b"message".as_ref()).unwrap();
+    let second = cipher.encrypt(&nonce, b"message".as_ref()).unwrap();
+    assert_eq!(first, second);
+    let third = cipher.encrypt(&nonce, b"messagf".as_ref()).unwrap();
+    assert_ne!(first[..16], third[..16]);
+}
+
+#[test]
+fn nonce_and_associated_data_are_bound() {
+    let cipher = AsconSiv::<U16>::new(&AsconSivKey::from(*KEY));
+    let nonce = AsconSivNonce::from(*b"unique nonce 012");
+    let other_nonce = AsconSivNonce::from(*b"unique nonce 013");
+    let ciphertext = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: b"message",
+                aad: b"ad",
+            },
+        )
+        .unwrap();
+    assert_ne!(
+        ciphertext,
+        cipher
+            .encrypt(
+                &other_nonce,
+                Payload {
+                    msg: b"message",
+                    aad: b"ad"
+                }
+            )
+            .unwrap()
+    );
+    assert!(
+        cipher
+            .decrypt(
+                &other_nonce,
+                Payload {
+                    msg: &ciphertext,
+                    aad: b"ad"
+                }
+            )
+            .is_err()
+    );
+    assert!(
+        cipher
+            .decrypt(
+                &nonce,
+                Payload {
+                    msg: &ciphertext,
+                    aad: b"da"
+                }
+            )
+            .is_err()
+    );
+    // moving bytes between nonce and associated data changes the synthetic IV
+    let cipher = AsconSiv::<U0>::new(&AsconSivKey::from(*KEY));
+    assert_ne!(
+        ciphertext,
+        cipher
+            .encrypt(
+                &Default::default(),
+                Payload {
+                    msg: b"message",
+                    aad: b"unique nonce 012ad"
+                }
+            )
+            .unwrap()
+    );
+}
+
+#[test]
+fn bad_tag() {
+    let cipher = AsconSiv::<U16>::new(&AsconSivKey::from(*KEY));
+    let nonce = AsconSivNonce::from(*b"unique nonce 012");
+    let mut buffer = *b"plaintext message";
+    let mut tag = cipher
+        .encrypt_inout_detached(&nonce, b"", buffer.as_mut_slice().into())
+        .unwrap();
+    tag[0] ^= 1;
+    assert!(
+        cipher
+            .decrypt_inout_detached(&nonce, b"", buffer.as_mut_slice().into(), &tag)
+            .is_err()
+    );
+    assert!(buffer.iter().all(|b| *b == 0));
+}
@@ -0,0 +1,108 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "alloc")]
+
+use ascon_aead::{
+    AsconAead128, AsconAead128Key, TruncatedAsconAead128,
+    aead::consts::U8,
+    stream::{StreamDecryptor, StreamEncryptor, StreamNoncePrefix},
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE_PREFIX: &[u8; 11] = b"unique 0123";
+
+fn encrypt_segments(segments: &[&[u8]]) -> Vec<Vec<u8>> {
+    let key = AsconAead128Key::from(*KEY);
+    let nonce_prefix = StreamNoncePrefix::from(*NONCE_PREFIX);
+
+    let mut encryptor = StreamEncryptor::<AsconAead128>::new(&key, &nonce_prefix);
+    let (last, segments) = segments.split_last().unwrap();
+    let mut ciphertexts: Vec<_> = segments
+        .iter()
+        .map(|segment| encryptor.encrypt_next(*segment).unwrap())
+        .collect();
+    ciphertexts.push(encryptor.encrypt_last(*last).unwrap());
+    ciphertexts
+}
+
+fn new_decryptor() -> StreamDecryptor<AsconAead128> {
+    StreamDecryptor::new(
+        &AsconAead128Key::from(*KEY),
+        &StreamNoncePrefix::from(*NONCE_PREFIX),
+    )
+}
+
+#[test]
+fn stream_roundtrip() {
+    let segments: [&[u8]; 4] = [b"first", b"", b"third segment", b"last"];
+    let ciphertexts = encrypt_segments(&segments);
+
+    let mut decryptor = new_decryptor();
+    for (ciphertext, segment) in ciphertexts[..3].iter().zip(segments) {
+        assert_eq!(
+            decryptor.decrypt_next(ciphertext.as_slice()).unwrap(),
+            segment
+        );
+    }
+    assert_eq!(
+        decryptor.decrypt_last(ciphertexts[3].as_slice()).unwrap(),
+        segments[3]
+    );
+}
+
+#[test]
+fn stream_reordered() {
+    let ciphertexts = encrypt_segments(&[b"first", b"second", b"last"]);
+
+    let mut decryptor = new_decryptor();
+    assert!(decryptor.decrypt_next(ciphertexts[1].as_slice()).is_err());
+    // failed decryptions do not advance the decryptor
+    assert!(decryptor.decrypt_next(ciphertexts[0].as_slice()).is_ok());
+    assert!(decryptor.decrypt_next(ciphertexts[0].as_slice()).is_err());
+}
+
+#[test]
+fn stream_dropped_segment() {
+    let ciphertexts = encrypt_segments(&[b"first", b"second", b"last"]);
+
+    let mut decryptor = new_decryptor();
+    assert!(decryptor.decrypt_next(ciphertexts[0].as_slice()).is_ok());
+    assert!(decryptor.decrypt_last(ciphertexts[2].as_slice()).is_err());
+}
+
+#[test]
+fn stream_truncated() {
+    let ciphertexts = encrypt_segments(&[b"first", b"second", b"last"]);
+
+    // the last segment of the truncated stream is not marked as last segment
+    let mut decryptor = new_decryptor();
+    assert!(decryptor.decrypt_next(ciphertexts[0].as_slice()).is_ok());
+    assert!(decryptor.decrypt_last(ciphertexts[1].as_slice()).is_err());
+
+    // the last segment cannot be decrypted as regular segment
+    let mut decryptor = new_decryptor();
+    assert!(decryptor.decrypt_next(ciphertexts[0].as_slice()).is_ok());
+    assert!(decryptor.decrypt_next(ciphertexts[1].as_slice()).is_ok());
+    assert!(decryptor.decrypt_next(ciphertexts[2].as_slice()).is_err());
+}
+
+#[test]
+fn stream_in_place_truncated_tag() {
+    type Aead = TruncatedAsconAead128<U8>;
+    let key = AsconAead128Key::from(*KEY);
+    let nonce_prefix = StreamNoncePrefix::from(*NONCE_PREFIX);
+
+    let mut encryptor = StreamEncryptor::<Aead>::new(&key, &nonce_prefix);
+    let mut first = b"first".to_vec();
+    encryptor.encrypt_next_in_place(b"ad", &mut first).unwrap();
+    let mut last = b"last".to_vec();
+    encryptor.encrypt_last_in_place(b"ad", &mut last).unwrap();
+    assert_eq!(first.len(), 5 + 8);
+
+    let mut decryptor = StreamDecryptor::<Aead>::new(&key, &nonce_prefix);
+    decryptor.decrypt_next_in_place(b"ad", &mut first).unwrap();
+    decryptor.decrypt_last_in_place(b"ad", &mut last).unwrap();
+    assert_eq!(first, b"first");
+    assert_eq!(last, b"last");
+}
@@ -0,0 +1,180 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "tokio")]
+
+use std::{
+    future::Future,
+    io::{Read, Write},
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use ascon_aead::{
+    AsconAead128Key,
+    io::{AsyncDecryptReader, AsyncEncryptWriter, DecryptingReader, EncryptingWriter, HEADER_SIZE},
+    stream::StreamNoncePrefix,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, duplex};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE_PREFIX: &[u8; 11] = b"unique 0123";
+const CHUNK_SIZE: usize = 32;
+
+fn plaintext(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+fn new_writer<W: tokio::io::AsyncWrite + Unpin>(inner: W) -> AsyncEncryptWriter<W> {
+    AsyncEncryptWriter::with_chunk_size(
+        inner,
+        &AsconAead128Key::from(*KEY),
+        &StreamNoncePrefix::from(*NONCE_PREFIX),
+        CHUNK_SIZE,
+    )
+    .unwrap()
+}
+
+async fn encrypt(plaintext: &[u8]) -> Vec<u8> {
+    let mut writer = new_writer(Vec::new());
+    writer.write_all(plaintext).await.unwrap();
+    writer.shutdown().await.unwrap();
+    writer.into_inner()
+}
+
+async fn decrypt(ciphertext: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut reader = AsyncDecryptReader::new(ciphertext, &AsconAead128Key::from(*KEY));
+    let mut plaintext = Vec::new();
+    reader.read_to_end(&mut plaintext).await?;
+    Ok(plaintext)
+}
+
+#[tokio::test]
+async fn roundtrip() {
+    for len in [0, 1, CHUNK_SIZE, CHUNK_SIZE + 1, 4 * CHUNK_SIZE + 3] {
+        let plaintext = plaintext(len);
+        let ciphertext = encrypt(&plaintext).await;
+        assert_eq!(decrypt(&ciphertext).await.unwrap(), plaintext);
+    }
+}
+
+#[tokio::test]
+async fn compatible_with_std_io() {
+    let plaintext = plaintext(3 * CHUNK_SIZE + 5);
+    let ciphertext = encrypt(&plaintext).await;
+
+    let mut writer = EncryptingWriter::with_chunk_size(
+        Vec::new(),
+        &AsconAead128Key::from(*KEY),
+        &StreamNoncePrefix::from(*NONCE_PREFIX),
+        CHUNK_SIZE,
+    )
+    .unwrap();
+    writer.write_all(&plaintext).unwrap();
+    assert_eq!(writer.finish().unwrap(), ciphertext);
+
+    let mut reader = DecryptingReader::new(ciphertext.as_slice(), &AsconAead128Key::from(*KEY));
+    let mut decrypted = Vec::new();
+    reader.read_to_end(&mut decrypted).unwrap();
+    assert_eq!(decrypted, plaintext);
+}
+
+#[tokio::test]
+async fn duplex_pipe() {
+    let plaintext = plaintext(1000);
+    // a small pipe forces partial reads and writes
+    let (client, server) = duplex(7);
+
+    let expected = plaintext.clone();
+    let sender = tokio::spawn(async move {
+        let mut writer = new_writer(client);
+        for chunk in expected.chunks(13) {
+            writer.write_all(chunk).await.unwrap();
+        }
+        writer.shutdown().await.unwrap();
+    });
+
+    let mut reader = AsyncDecryptReader::new(server, &AsconAead128Key::from(*KEY));
+    let mut decrypted = Vec::new();
+    reader.read_to_end(&mut decrypted).await.unwrap();
+    sender.await.unwrap();
+    assert_eq!(decrypted, plaintext);
+}
+
+#[tokio::test]
+async fn cancelled_read() {
+    let plaintext = plaintext(2 * CHUNK_SIZE + 1);
+    let ciphertext = encrypt(&plaintext).await;
+    let (mut client, server) = duplex(1024);
+    let mut reader = AsyncDecryptReader::new(server, &AsconAead128Key::from(*KEY));
+
+    // a read is pending with a partial chunk and then dropped
+    client
+        .write_all(&ciphertext[..HEADER_SIZE + 10])
+        .await
+        .unwrap();
+    let mut buffer = [0u8; 8];
+    {
+        let read = pin!(reader.read(&mut buffer));
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(read.poll(&mut cx).is_pending());
+    }
+
+    client
+        .write_all(&ciphertext[HEADER_SIZE + 10..])
+        .await
+        .unwrap();
+    drop(client);
+    let mut decrypted = Vec::new();
+    reader.read_to_end(&mut decrypted).await.unwrap();
+    assert_eq!(decrypted, plaintext);
+}
+
+#[tokio::test]
+async fn cancelled_write() {
+    let plaintext = plaintext(3 * CHUNK_SIZE);
+    let (client, mut server) = duplex(8);
+    let mut writer = new_writer(client);
+
+    // writes are accepted until the pipe is full and a pending write is dropped
+    let mut written = 0;
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        let write = pin!(writer.write(&plaintext[written..]));
+        match write.poll(&mut cx) {
+            Poll::Ready(len) => written += len.unwrap(),
+            Poll::Pending => break,
+        }
+    }
+
+    let reader = tokio::spawn(async move {
+        let mut ciphertext = Vec::new();
+        server.read_to_end(&mut ciphertext).await.unwrap();
+        ciphertext
+    });
+    writer.write_all(&plaintext[written..]).await.unwrap();
+    writer.shutdown().await.unwrap();
+    drop(writer);
+    assert_eq!(reader.await.unwrap(), encrypt(&plaintext).await);
+}
+
+#[tokio::test]
+async fn detects_modifications() {
+    let ciphertext = encrypt(&plaintext(2 * CHUNK_SIZE + 3)).await;
+    for index in 0..ciphertext.len() {
+        let mut modified = ciphertext.clone();
+        modified[index] ^= 1;
+        assert!(decrypt(&modified).await.is_err(), "index {index}");
+    }
+    for len in [0, HEADER_SIZE, HEADER_SIZE + CHUNK_SIZE + 16] {
+        assert!(decrypt(&ciphertext[..len]).await.is_err());
+    }
+
+    // no plaintext of an unauthentic chunk is returned
+    let mut modified = ciphertext.clone();
+    modified[HEADER_SIZE] ^= 1;
+    let mut reader = AsyncDecryptReader::new(modified.as_slice(), &AsconAead128Key::from(*KEY));
+    let mut buffer = [0u8; 1];
+    assert!(reader.read(&mut buffer).await.is_err());
+    assert!(reader.read(&mut buffer).await.is_err());
+}
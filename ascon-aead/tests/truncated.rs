@@ -0,0 +1,171 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use ascon_aead::{
+    AsconAead128, BitTruncatedAsconAead128, DynTagAsconAead128, TruncatedAsconAead128,
+    aead::{
+        AeadInOut, KeyInit,
+        consts::{U5, U8, U32, U36, U60, U128},
+    },
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE: &[u8; 16] = b"unique nonce 012";
+const MESSAGE: &[u8] = b"plaintext message";
+
+#[test]
+fn bit_truncated_matches_byte_truncated() {
+    let mut buffer = [0u8; MESSAGE.len()];
+    buffer.copy_from_slice(MESSAGE);
+    let tag = TruncatedAsconAead128::<U5>::new(KEY.into())
+        .encrypt_inout_detached(NONCE.into(), b"ad", buffer.as_mut_slice().into())
+        .unwrap();
+
+    let mut buffer36 = [0u8; MESSAGE.len()];
+    buffer36.copy_from_slice(MESSAGE);
+    let tag36 = BitTruncatedAsconAead128::<U36>::new(KEY.into())
+        .encrypt_inout_detached(NONCE.into(), b"ad", buffer36.as_mut_slice().into())
+        .unwrap();
+    assert_eq!(buffer36, buffer);
+    assert_eq!(tag36[..4], tag[..4]);
+    assert_eq!(tag36[4], tag[4] & 0x0f);
+
+    let tag = TruncatedAsconAead128::<U8>::new(KEY.into())
+        .encrypt_inout_detached(NONCE.into(), b"ad", MESSAGE.to_vec().as_mut_slice().into())
+        .unwrap();
+    let tag60 = BitTruncatedAsconAead128::<U60>::new(KEY.into())
+        .encrypt_inout_detached(NONCE.into(), b"ad", MESSAGE.to_vec().as_mut_slice().into())
+        .unwrap();
+    assert_eq!(tag60[..7], tag[..7]);
+    assert_eq!(tag60[7], tag[7] & 0x0f);
+}
+
+#[test]
+fn bit_truncated_whole_bytes() {
+    let tag = AsconAead128::new(KEY.into())
+        .encrypt_inout_detached(NONCE.into(), b"", MESSAGE.to_vec().as_mut_slice().into())
+        .unwrap();
+    let tag128 = BitTruncatedAsconAead128::<U128>::new(KEY.into())
+        .encrypt_inout_detached(NONCE.into(), b"", MESSAGE.to_vec().as_mut_slice().into())
+        .unwrap();
+    assert_eq!(tag128, tag);
+
+    let tag32 = BitTruncatedAsconAead128::<U32>::new(KEY.into())
+        .encrypt_inout_detached(NONCE.into(), b"", MESSAGE.to_vec().as_mut_slice().into())
+        .unwrap();
+    assert_eq!(tag32[..], tag[..4]);
+}
+
+#[test]
+fn bit_truncated_verify_significant_bits() {
+    let cipher = BitTruncatedAsconAead128::<U36>::new(KEY.into());
+    let mut buffer = [0u8; MESSAGE.len()];
+    buffer.copy_from_slice(MESSAGE);
+    let tag = cipher
+        .encrypt_inout_detached(NONCE.into(), b"", buffer.as_mut_slice().into())
+        .unwrap();
+    let ciphertext = buffer;
+
+    // insignificant bits are ignored
+    let mut other_tag = tag;
+    other_tag[4] ^= 0xf0;
+    assert!(
+        cipher
+            .decrypt_inout_detached(NONCE.into(), b"", buffer.as_mut_slice().into(), &other_tag)
+            .is_ok()
+    );
+    assert_eq!(buffer, MESSAGE);
+
+    // every significant bit is checked
+    for bit in 0..36 {
+        let mut buffer = ciphertext;
+        let mut bad_tag = tag;
+        bad_tag[bit / 8] ^= 1 << (bit % 8);
+        assert!(
+            cipher
+                .decrypt_inout_detached(NONCE.into(), b"", buffer.as_mut_slice().into(), &bad_tag)
+                .is_err()
+        );
+        assert!(buffer.iter().all(|b| *b == 0));
+    }
+}
+
+#[test]
+fn dyn_tag_matches_truncated() {
+    let aead = AsconAead128::new(KEY.into());
+    for tag_size in 4..=16 {
+        let cipher = DynTagAsconAead128::new(KEY.into(), tag_size).unwrap();
+        assert_eq!(cipher.tag_size(), tag_size);
+        for len in 0..=40 {
+            let msg: Vec<u8> = (0..len).collect();
+            let mut expected = msg.clone();
+            let expected_tag = aead
+                .encrypt_inout_detached(NONCE.into(), b"ad", expected.as_mut_slice().into())
+                .unwrap();
+
+            let mut buffer = msg.clone();
+            let mut tag = vec![0u8; tag_size];
+            cipher
+                .encrypt_in_place_detached(NONCE.into(), b"ad", &mut buffer, &mut tag)
+                .unwrap();
+            assert_eq!(buffer, expected);
+            assert_eq!(tag, expected_tag[..tag_size]);
+
+            cipher
+                .decrypt_in_place_detached(NONCE.into(), b"ad", &mut buffer, &tag)
+                .unwrap();
+            assert_eq!(buffer, msg);
+        }
+    }
+
+    let expected_tag = TruncatedAsconAead128::<U5>::new(KEY.into())
+        .encrypt_inout_detached(NONCE.into(), b"", MESSAGE.to_vec().as_mut_slice().into())
+        .unwrap();
+    let mut tag = [0u8; 5];
+    DynTagAsconAead128::new(KEY.into(), 5)
+        .unwrap()
+        .encrypt_in_place_detached(NONCE.into(), b"", &mut MESSAGE.to_vec(), &mut tag)
+        .unwrap();
+    assert_eq!(tag, expected_tag.as_slice());
+}
+
+#[test]
+fn dyn_tag_invalid_lengths() {
+    assert!(DynTagAsconAead128::new(KEY.into(), 3).is_err());
+    assert!(DynTagAsconAead128::new(KEY.into(), 17).is_err());
+    assert!(DynTagAsconAead128::new_from_slice(&KEY[..15], 16).is_err());
+
+    let cipher = DynTagAsconAead128::new(KEY.into(), 8).unwrap();
+    let mut buffer = *b"plaintext message";
+    assert!(
+        cipher
+            .encrypt_in_place_detached(NONCE.into(), b"", &mut buffer, &mut [0u8; 16])
+            .is_err()
+    );
+    let mut tag = [0u8; 8];
+    cipher
+        .encrypt_in_place_detached(NONCE.into(), b"", &mut buffer, &mut tag)
+        .unwrap();
+    assert!(
+        cipher
+            .decrypt_in_place_detached(NONCE.into(), b"", &mut buffer.clone(), &tag[..7])
+            .is_err()
+    );
+}
+
+#[test]
+fn dyn_tag_bad_tag() {
+    let cipher = DynTagAsconAead128::new(KEY.into(), 6).unwrap();
+    let mut buffer = *b"plaintext message";
+    let mut tag = [0u8; 6];
+    cipher
+        .encrypt_in_place_detached(NONCE.into(), b"", &mut buffer, &mut tag)
+        .unwrap();
+    tag[5] ^= 0x80;
+    assert!(
+        cipher
+            .decrypt_in_place_detached(NONCE.into(), b"", &mut buffer, &tag)
+            .is_err()
+    );
+    assert!(buffer.iter().all(|b| *b == 0));
+}
@@ -0,0 +1,66 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use ascon_aead::{
+    AsconAead128, AsconAead128Key, AsconAead128Nonce,
+    aead::{AeadInOut, KeyInit},
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE: &[u8; 16] = b"unique nonce 012";
+
+#[test]
+fn verify_detached() {
+    let cipher = AsconAead128::new(&AsconAead128Key::from(*KEY));
+    let nonce = AsconAead128Nonce::from(*NONCE);
+    for len in 0..=40 {
+        let mut ciphertext: Vec<u8> = (0..len).collect();
+        let tag = cipher
+            .encrypt_inout_detached(&nonce, b"ad", ciphertext.as_mut_slice().into())
+            .unwrap();
+        assert!(
+            cipher
+                .verify_detached(&nonce, b"ad", &ciphertext, &tag)
+                .is_ok()
+        );
+        assert!(
+            cipher
+                .verify_detached(&nonce, b"da", &ciphertext, &tag)
+                .is_err()
+        );
+
+        let mut bad_tag = tag;
+        bad_tag[15] ^= 0x80;
+        assert!(
+            cipher
+                .verify_detached(&nonce, b"ad", &ciphertext, &bad_tag)
+                .is_err()
+        );
+
+        for i in 0..ciphertext.len() {
+            let mut bad_ciphertext = ciphertext.clone();
+            bad_ciphertext[i] ^= 1;
+            assert!(
+                cipher
+                    .verify_detached(&nonce, b"ad", &bad_ciphertext, &tag)
+                    .is_err()
+            );
+        }
+    }
+}
+
+#[test]
+fn verify_appended_tag() {
+    let cipher = AsconAead128::new(&AsconAead128Key::from(*KEY));
+    let nonce = AsconAead128Nonce::from(*NONCE);
+    for len in 0..=40 {
+        let mut ciphertext: Vec<u8> = (0..len).collect();
+        let tag = cipher
+            .encrypt_inout_detached(&nonce, b"ad", ciphertext.as_mut_slice().into())
+            .unwrap();
+        ciphertext.extend_from_slice(&tag);
+        assert!(cipher.verify(&nonce, b"ad", &ciphertext).is_ok());
+        assert!(cipher.verify(&nonce, b"ad", &ciphertext[1..]).is_err());
+    }
+    assert!(cipher.verify(&nonce, b"ad", &[0u8; 15]).is_err());
+}
@@ -0,0 +1,74 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use ascon_aead::{
+    AsconAead128, AsconAead128Key, AsconAead128Nonce, VerifyFirstAsconAead128,
+    aead::{AeadInOut, KeyInit, inout::InOutBuf},
+};
+
+const KEY: &[u8; 16] = b"very secret key.";
+const NONCE: &[u8; 16] = b"unique nonce 012";
+
+#[test]
+fn matches_ascon_aead128() {
+    let key = AsconAead128Key::from(*KEY);
+    let nonce = AsconAead128Nonce::from(*NONCE);
+    let aead = AsconAead128::new(&key);
+    let cipher = VerifyFirstAsconAead128::new(&key);
+    for len in 0..=40 {
+        let msg: Vec<u8> = (0..len).collect();
+        let mut ciphertext = msg.clone();
+        let tag = aead
+            .encrypt_inout_detached(&nonce, b"ad", ciphertext.as_mut_slice().into())
+            .unwrap();
+
+        let mut buffer = msg.clone();
+        assert_eq!(
+            cipher
+                .encrypt_inout_detached(&nonce, b"ad", buffer.as_mut_slice().into())
+                .unwrap(),
+            tag
+        );
+        assert_eq!(buffer, ciphertext);
+
+        cipher
+            .decrypt_inout_detached(&nonce, b"ad", buffer.as_mut_slice().into(), &tag)
+            .unwrap();
+        assert_eq!(buffer, msg);
+    }
+}
+
+#[test]
+fn output_untouched_on_failure() {
+    let key = AsconAead128Key::from(*KEY);
+    let nonce = AsconAead128Nonce::from(*NONCE);
+    let cipher = VerifyFirstAsconAead128::from(AsconAead128::new(&key));
+    for len in 0..=40 {
+        let mut ciphertext: Vec<u8> = (0..len).collect();
+        let mut tag = cipher
+            .encrypt_inout_detached(&nonce, b"ad", ciphertext.as_mut_slice().into())
+            .unwrap();
+
+        let mut output = vec![0xaa; len as usize];
+        assert!(
+            cipher
+                .decrypt_inout_detached(
+                    &nonce,
+                    b"da",
+                    InOutBuf::new(&ciphertext, &mut output).unwrap(),
+                    &tag
+                )
+                .is_err()
+        );
+        assert!(output.iter().all(|b| *b == 0xaa));
+
+        tag[15] ^= 0x80;
+        let mut buffer = ciphertext.clone();
+        assert!(
+            cipher
+                .decrypt_inout_detached(&nonce, b"ad", buffer.as_mut_slice().into(), &tag)
+                .is_err()
+        );
+        assert_eq!(buffer, ciphertext);
+    }
+}
@@ -6,6 +6,7 @@ use std::hint::black_box;
 use ascon_aead::{
     AsconAead128,
     aead::{Aead, AeadInOut, Generate, Key, KeyInit, Nonce},
+    batch::BatchItem,
 };
 use criterion::{Bencher, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
 use rand::RngCore;
@@ -70,9 +71,52 @@ fn criterion_bench_ascon128_inplace(c: &mut Criterion) {
     criterion_benchmark_inplace::<AsconAead128>(c, "AsconAead128 (inplace)");
 }
 
+fn criterion_bench_ascon128_batch(c: &mut Criterion) {
+    const PACKETS: usize = 64;
+    let mut rng = rand::rng();
+    let key = Key::<AsconAead128>::generate();
+    let cipher = AsconAead128::new(&key);
+    let nonces: Vec<_> = (0..PACKETS)
+        .map(|_| Nonce::<AsconAead128>::generate())
+        .collect();
+
+    let mut group = c.benchmark_group("AsconAead128 (batch)");
+    for size in [16, 32, 64, 128].iter() {
+        let mut buffers = vec![vec![0u8; *size]; PACKETS];
+        buffers
+            .iter_mut()
+            .for_each(|buffer| rng.fill_bytes(buffer.as_mut_slice()));
+
+        group.throughput(Throughput::Bytes((size * PACKETS) as u64));
+        group.bench_with_input(BenchmarkId::new("one-shot", size), size, |b, _| {
+            b.iter(|| {
+                for (nonce, buffer) in nonces.iter().zip(buffers.iter_mut()) {
+                    let _ = black_box(cipher.encrypt_inout_detached(
+                        nonce,
+                        b"",
+                        buffer.as_mut_slice().into(),
+                    ));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batch", size), size, |b, _| {
+            b.iter(|| {
+                let mut items: Vec<_> = nonces
+                    .iter()
+                    .zip(buffers.iter_mut())
+                    .map(|(nonce, buffer)| BatchItem::new(nonce, b"", buffer))
+                    .collect();
+                black_box(cipher.encrypt_batch(&mut items))
+            })
+        });
+    }
+    group.finish();
+}
+
 criterion_group!(
     bench_ascon128,
     criterion_bench_ascon128,
     criterion_bench_ascon128_inplace,
+    criterion_bench_ascon128_batch,
 );
 criterion_main!(bench_ascon128);
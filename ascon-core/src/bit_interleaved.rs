@@ -0,0 +1,178 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Bit-interleaved implementation of the round function
+//!
+//! Each 64 bit word is split into two 32 bit words holding the even and the odd bits,
+//! respectively. Rotations of 64 bit words then become rotations of 32 bit words, which avoids
+//! emulated 64 bit rotations on 32 bit platforms.
+
+use crate::STATE_WORDS;
+
+/// A 64 bit word split into its even bits (index 0) and its odd bits (index 1)
+pub(crate) type Lane = [u32; 2];
+
+/// Move the even bits of `x` to the lower half and the odd bits to the upper half.
+#[inline(always)]
+const fn unzip(mut x: u32) -> u32 {
+    let t = (x ^ (x >> 1)) & 0x22222222;
+    x ^= t ^ (t << 1);
+    let t = (x ^ (x >> 2)) & 0x0c0c0c0c;
+    x ^= t ^ (t << 2);
+    let t = (x ^ (x >> 4)) & 0x00f000f0;
+    x ^= t ^ (t << 4);
+    let t = (x ^ (x >> 8)) & 0x0000ff00;
+    x ^ t ^ (t << 8)
+}
+
+/// Inverse of [`unzip`]
+#[inline(always)]
+const fn zip(mut x: u32) -> u32 {
+    let t = (x ^ (x >> 8)) & 0x0000ff00;
+    x ^= t ^ (t << 8);
+    let t = (x ^ (x >> 4)) & 0x00f000f0;
+    x ^= t ^ (t << 4);
+    let t = (x ^ (x >> 2)) & 0x0c0c0c0c;
+    x ^= t ^ (t << 2);
+    let t = (x ^ (x >> 1)) & 0x22222222;
+    x ^ t ^ (t << 1)
+}
+
+/// Convert a 64 bit word to its bit-interleaved representation.
+#[inline(always)]
+const fn to_lane(x: u64) -> Lane {
+    let lo = unzip(x as u32);
+    let hi = unzip((x >> 32) as u32);
+    [(lo & 0xffff) | (hi << 16), (lo >> 16) | (hi & 0xffff0000)]
+}
+
+/// Convert a bit-interleaved word back to a 64 bit word.
+#[inline(always)]
+const fn from_lane(x: Lane) -> u64 {
+    let lo = zip((x[0] & 0xffff) | (x[1] << 16));
+    let hi = zip((x[0] >> 16) | (x[1] & 0xffff0000));
+    ((hi as u64) << 32) | lo as u64
+}
+
+/// Convert the state to its bit-interleaved representation.
+#[inline(always)]
+pub(crate) const fn to_interleaved(x: [u64; STATE_WORDS]) -> [Lane; STATE_WORDS] {
+    [
+        to_lane(x[0]),
+        to_lane(x[1]),
+        to_lane(x[2]),
+        to_lane(x[3]),
+        to_lane(x[4]),
+    ]
+}
+
+/// Convert the bit-interleaved state back to 64 bit words.
+#[inline(always)]
+pub(crate) const fn from_interleaved(x: [Lane; STATE_WORDS]) -> [u64; STATE_WORDS] {
+    [
+        from_lane(x[0]),
+        from_lane(x[1]),
+        from_lane(x[2]),
+        from_lane(x[3]),
+        from_lane(x[4]),
+    ]
+}
+
+/// Rotate a bit-interleaved word to the right by `n` bits.
+#[inline(always)]
+const fn rotate_right(x: Lane, n: u32) -> Lane {
+    if n % 2 == 0 {
+        [x[0].rotate_right(n / 2), x[1].rotate_right(n / 2)]
+    } else {
+        [x[1].rotate_right(n / 2), x[0].rotate_right(n / 2 + 1)]
+    }
+}
+
+/// XOR two bit-interleaved words.
+#[inline(always)]
+const fn xor(x: Lane, y: Lane) -> Lane {
+    [x[0] ^ y[0], x[1] ^ y[1]]
+}
+
+/// Apply the linear layer to a single word.
+#[inline(always)]
+const fn linear(x: Lane, n: u32, m: u32) -> Lane {
+    xor(x, rotate_right(xor(x, rotate_right(x, n)), m))
+}
+
+/// Ascon's round function on the bit-interleaved state
+pub(crate) const fn round(x: [Lane; STATE_WORDS], c: u64) -> [Lane; STATE_WORDS] {
+    let c = to_lane(c);
+    let mut t = [[0u32; 2]; STATE_WORDS];
+
+    // S-box layer on the even and odd halves
+    let mut i = 0;
+    while i < 2 {
+        let x0 = x[0][i] ^ x[4][i];
+        let x2 = x[2][i] ^ x[1][i] ^ c[i]; // with round constant
+        let x4 = x[4][i] ^ x[3][i];
+
+        let tx0 = x0 ^ (!x[1][i] & x2);
+        let tx1 = x[1][i] ^ (!x2 & x[3][i]);
+        let tx2 = x2 ^ (!x[3][i] & x4);
+        let tx3 = x[3][i] ^ (!x4 & x0);
+        let tx4 = x4 ^ (!x0 & x[1][i]);
+        t[0][i] = tx0 ^ tx4;
+        t[1][i] = tx1 ^ tx0;
+        t[2][i] = !tx2;
+        t[3][i] = tx3 ^ tx2;
+        t[4][i] = tx4;
+        i += 1;
+    }
+
+    // linear layer
+    [
+        linear(t[0], 9, 19),
+        linear(t[1], 22, 39),
+        linear(t[2], 5, 1),
+        linear(t[3], 7, 10),
+        linear(t[4], 34, 7),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATE: [u64; STATE_WORDS] = [
+        0x0123456789abcdef,
+        0x23456789abcdef01,
+        0x456789abcdef0123,
+        0x6789abcdef012345,
+        0x89abcde01234567f,
+    ];
+
+    #[test]
+    fn convert() {
+        assert_eq!(to_lane(0x5555555555555555), [u32::MAX, 0]);
+        assert_eq!(to_lane(0xaaaaaaaaaaaaaaaa), [0, u32::MAX]);
+        for x in STATE {
+            assert_eq!(from_lane(to_lane(x)), x);
+            for n in 0..64 {
+                assert_eq!(
+                    from_lane(rotate_right(to_lane(x), n)),
+                    x.rotate_right(n),
+                    "{n}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn matches_round() {
+        let mut x = STATE;
+        let mut y = to_interleaved(STATE);
+        for c in [
+            0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69, 0x5a, 0x4b,
+        ] {
+            x = crate::round(x, c);
+            y = round(y, c);
+            assert_eq!(from_interleaved(y), x);
+        }
+    }
+}
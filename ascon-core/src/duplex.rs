@@ -0,0 +1,416 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Duplex construction on top of Ascon's permutation
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{STATE_SIZE, State, WORD_SIZE};
+
+/// Produce mask for padding.
+#[inline(always)]
+const fn pad(n: usize) -> u64 {
+    0x01_u64 << (8 * n)
+}
+
+/// Load a word from up to 8 bytes.
+#[inline(always)]
+fn load(input: &[u8]) -> u64 {
+    let mut tmp = [0u8; WORD_SIZE];
+    tmp[..input.len()].copy_from_slice(input);
+    u64::from_le_bytes(tmp)
+}
+
+/// Apply the permutation with the given number of rounds.
+#[inline(always)]
+fn permute(state: &mut State, rounds: usize) {
+    match rounds {
+        12 => state.permute_12(),
+        8 => state.permute_8(),
+        _ => state.permute_n(rounds),
+    }
+}
+
+/// Duplex object operating on Ascon's state
+///
+/// Provides the byte-level operations of the sponge and duplex constructions used by Ascon's
+/// modes: absorbing data with padding, squeezing output, and encrypting and decrypting data. The
+/// first `RATE` bytes of the state form the rate, the remaining bytes form the capacity. The
+/// permutation with `ROUNDS_B` rounds is applied between blocks, whereas the permutation with
+/// `ROUNDS_A` rounds is available for initialization and finalization. Bytes are loaded in
+/// little-endian byte order as specified in SP 800-232.
+///
+/// `RATE` needs to be a non-zero multiple of 8 smaller than 40, the round counts need to be at
+/// most 12.
+///
+/// For example, Ascon-AEAD128 is implemented as follows:
+///
+/// ```
+/// use ascon_core::{Duplex, State};
+///
+/// let key = *b"very secret key.";
+/// let nonce = *b"unique nonce 012";
+/// let load = |bytes: &[u8]| u64::from_le_bytes(bytes.try_into().unwrap());
+///
+/// let initialize = || {
+///     let mut duplex = Duplex::<16>::new(State::new(
+///         0x00001000808c0001,
+///         load(&key[..8]),
+///         load(&key[8..]),
+///         load(&nonce[..8]),
+///         load(&nonce[8..]),
+///     ));
+///     duplex.permute_a();
+///     duplex.xor_key_tail(&key);
+///
+///     // associated data
+///     duplex.absorb_padded(b"associated data");
+///     duplex.permute_b();
+///     duplex.domain_separate();
+///     duplex
+/// };
+///
+/// let finalize = |mut duplex: Duplex<16>| {
+///     duplex.xor_key_after_rate(&key);
+///     duplex.permute_a();
+///     duplex.xor_key_tail(&key);
+///     let mut tag = [0u8; 16];
+///     duplex.read_bytes(24, &mut tag);
+///     tag
+/// };
+///
+/// // encryption
+/// let mut buffer = *b"a plaintext message";
+/// let mut duplex = initialize();
+/// duplex.duplex_encrypt(&mut buffer);
+/// let tag = finalize(duplex);
+/// assert_ne!(&buffer, b"a plaintext message");
+///
+/// // decryption
+/// let mut duplex = initialize();
+/// duplex.duplex_decrypt(&mut buffer);
+/// assert_eq!(&buffer, b"a plaintext message");
+/// assert_eq!(finalize(duplex), tag);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct Duplex<const RATE: usize, const ROUNDS_A: usize = 12, const ROUNDS_B: usize = 8> {
+    state: State,
+}
+
+impl<const RATE: usize, const ROUNDS_A: usize, const ROUNDS_B: usize>
+    Duplex<RATE, ROUNDS_A, ROUNDS_B>
+{
+    /// Instantiate a duplex object from the given state.
+    pub fn new(state: State) -> Self {
+        const {
+            assert!(RATE > 0 && RATE % WORD_SIZE == 0 && RATE < STATE_SIZE);
+            assert!(ROUNDS_A <= 12 && ROUNDS_B <= 12);
+        }
+        Self { state }
+    }
+
+    /// Return the state.
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Return the state for modification.
+    pub fn state_mut(&mut self) -> &mut State {
+        &mut self.state
+    }
+
+    /// Consume the duplex object and return the state.
+    pub fn into_state(mut self) -> State {
+        // the state cannot be moved out if `Duplex` implements `Drop` via `ZeroizeOnDrop`
+        core::mem::take(&mut self.state)
+    }
+
+    /// Perform permutation with `ROUNDS_A` rounds.
+    pub fn permute_a(&mut self) {
+        permute(&mut self.state, ROUNDS_A);
+    }
+
+    /// Perform permutation with `ROUNDS_B` rounds.
+    pub fn permute_b(&mut self) {
+        permute(&mut self.state, ROUNDS_B);
+    }
+
+    /// XOR up to `RATE` bytes into the rate without applying the permutation.
+    ///
+    /// Panics if `block` is larger than `RATE` bytes.
+    pub fn absorb(&mut self, block: &[u8]) {
+        assert!(block.len() <= RATE);
+
+        for (word, chunk) in block.chunks(WORD_SIZE).enumerate() {
+            self.state[word] ^= load(chunk);
+        }
+    }
+
+    /// Absorb data with padding.
+    ///
+    /// The permutation with `ROUNDS_B` rounds is applied after each full block. The last block is
+    /// padded, but the permutation is not applied afterwards.
+    pub fn absorb_padded(&mut self, data: &[u8]) {
+        let mut blocks = data.chunks_exact(RATE);
+        for block in blocks.by_ref() {
+            self.absorb(block);
+            self.permute_b();
+        }
+
+        let last_block = blocks.remainder();
+        self.absorb(last_block);
+        self.pad(last_block.len());
+    }
+
+    /// Squeeze output from the rate.
+    ///
+    /// The permutation with `ROUNDS_B` rounds is applied between blocks, but not after the last
+    /// block.
+    pub fn squeeze(&mut self, output: &mut [u8]) {
+        for (index, block) in output.chunks_mut(RATE).enumerate() {
+            if index > 0 {
+                self.permute_b();
+            }
+            self.read_bytes(0, block);
+        }
+    }
+
+    /// Encrypt the buffer in-place.
+    ///
+    /// The permutation with `ROUNDS_B` rounds is applied after each full block. The last block is
+    /// padded, but the permutation is not applied afterwards.
+    pub fn duplex_encrypt(&mut self, buffer: &mut [u8]) {
+        let mut blocks = buffer.chunks_exact_mut(RATE);
+        for block in blocks.by_ref() {
+            self.encrypt_block(block);
+            self.permute_b();
+        }
+
+        let last_block = blocks.into_remainder();
+        self.encrypt_block(last_block);
+        self.pad(last_block.len());
+    }
+
+    /// Decrypt the buffer in-place.
+    ///
+    /// The permutation with `ROUNDS_B` rounds is applied after each full block. The last block is
+    /// padded, but the permutation is not applied afterwards.
+    pub fn duplex_decrypt(&mut self, buffer: &mut [u8]) {
+        let mut blocks = buffer.chunks_exact_mut(RATE);
+        for block in blocks.by_ref() {
+            self.decrypt_block(block);
+            self.permute_b();
+        }
+
+        let last_block = blocks.into_remainder();
+        self.decrypt_block(last_block);
+        self.pad(last_block.len());
+    }
+
+    /// XOR the key into the last bytes of the state.
+    ///
+    /// Panics if the key does not fit into the capacity.
+    pub fn xor_key_tail(&mut self, key: &[u8]) {
+        assert!(key.len() <= STATE_SIZE - RATE);
+
+        self.xor_bytes(STATE_SIZE - key.len(), key);
+    }
+
+    /// XOR the key into the bytes of the state following the rate.
+    ///
+    /// Panics if the key does not fit into the capacity.
+    pub fn xor_key_after_rate(&mut self, key: &[u8]) {
+        assert!(key.len() <= STATE_SIZE - RATE);
+
+        self.xor_bytes(RATE, key);
+    }
+
+    /// Apply the domain separation between associated data and message.
+    pub fn domain_separate(&mut self) {
+        self.state[4] ^= 0x8000000000000000;
+    }
+
+    /// XOR bytes into the state starting at the given byte offset.
+    ///
+    /// Panics if the bytes do not fit into the state.
+    pub fn xor_bytes(&mut self, offset: usize, bytes: &[u8]) {
+        assert!(bytes.len() <= STATE_SIZE && offset <= STATE_SIZE - bytes.len());
+
+        for (index, byte) in bytes.iter().enumerate() {
+            let position = offset + index;
+            self.state[position / WORD_SIZE] ^= (*byte as u64) << (8 * (position % WORD_SIZE));
+        }
+    }
+
+    /// Read bytes from the state starting at the given byte offset.
+    ///
+    /// Panics if the bytes do not fit into the state.
+    pub fn read_bytes(&self, offset: usize, bytes: &mut [u8]) {
+        assert!(bytes.len() <= STATE_SIZE && offset <= STATE_SIZE - bytes.len());
+
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            let position = offset + index;
+            *byte = (self.state[position / WORD_SIZE] >> (8 * (position % WORD_SIZE))) as u8;
+        }
+    }
+
+    /// Pad the rate after the given number of bytes.
+    #[inline(always)]
+    fn pad(&mut self, len: usize) {
+        debug_assert!(len < RATE);
+
+        self.state[len / WORD_SIZE] ^= pad(len % WORD_SIZE);
+    }
+
+    /// Encrypt up to `RATE` bytes.
+    #[inline(always)]
+    fn encrypt_block(&mut self, block: &mut [u8]) {
+        for (word, chunk) in block.chunks_mut(WORD_SIZE).enumerate() {
+            self.state[word] ^= load(chunk);
+            chunk.copy_from_slice(&self.state[word].to_le_bytes()[..chunk.len()]);
+        }
+    }
+
+    /// Decrypt up to `RATE` bytes.
+    #[inline(always)]
+    fn decrypt_block(&mut self, block: &mut [u8]) {
+        for (word, chunk) in block.chunks_mut(WORD_SIZE).enumerate() {
+            let ciphertext = load(chunk);
+            let plaintext = self.state[word] ^ ciphertext;
+            chunk.copy_from_slice(&plaintext.to_le_bytes()[..chunk.len()]);
+            // replace the first bytes of the word with the ciphertext
+            let mask = u64::MAX.checked_shl(8 * chunk.len() as u32).unwrap_or(0);
+            self.state[word] = (self.state[word] & mask) | ciphertext;
+        }
+    }
+}
+
+impl<const RATE: usize, const ROUNDS_A: usize, const ROUNDS_B: usize> From<State>
+    for Duplex<RATE, ROUNDS_A, ROUNDS_B>
+{
+    fn from(state: State) -> Self {
+        Self::new(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f,
+    ];
+    const NONCE: [u8; 16] = KEY;
+
+    fn initialize(associated_data: &[u8]) -> Duplex<16> {
+        let mut duplex = Duplex::<16>::new(State::new(
+            0x00001000808c0001,
+            load(&KEY[..8]),
+            load(&KEY[8..]),
+            load(&NONCE[..8]),
+            load(&NONCE[8..]),
+        ));
+        duplex.permute_a();
+        duplex.xor_key_tail(&KEY);
+        if !associated_data.is_empty() {
+            duplex.absorb_padded(associated_data);
+            duplex.permute_b();
+        }
+        duplex.domain_separate();
+        duplex
+    }
+
+    fn finalize(mut duplex: Duplex<16>) -> [u8; 16] {
+        duplex.xor_key_after_rate(&KEY);
+        duplex.permute_a();
+        duplex.xor_key_tail(&KEY);
+        let mut tag = [0u8; 16];
+        duplex.read_bytes(24, &mut tag);
+        tag
+    }
+
+    fn encrypt(plaintext: &[u8], associated_data: &[u8]) -> ([u8; 64], usize) {
+        let mut duplex = initialize(associated_data);
+
+        let mut output = [0u8; 64];
+        let len = plaintext.len();
+        output[..len].copy_from_slice(plaintext);
+        duplex.duplex_encrypt(&mut output[..len]);
+        output[len..len + 16].copy_from_slice(&finalize(duplex));
+        (output, len + 16)
+    }
+
+    fn decrypt(ciphertext: &[u8], associated_data: &[u8]) -> ([u8; 64], [u8; 16]) {
+        let mut duplex = initialize(associated_data);
+
+        let mut output = [0u8; 64];
+        output[..ciphertext.len()].copy_from_slice(ciphertext);
+        duplex.duplex_decrypt(&mut output[..ciphertext.len()]);
+        (output, finalize(duplex))
+    }
+
+    #[test]
+    fn ascon_aead128() {
+        let plaintext: [u8; 17] = core::array::from_fn(|i| i as u8);
+        let associated_data: [u8; 9] = core::array::from_fn(|i| i as u8);
+
+        let (output, len) = encrypt(b"", b"");
+        assert_eq!(
+            output[..len],
+            [
+                0x44, 0x27, 0xd6, 0x4b, 0x8e, 0x1e, 0x14, 0x51, 0xfc, 0x44, 0x59, 0x60, 0xf0, 0x83,
+                0x9b, 0xb0
+            ]
+        );
+
+        let expected = [
+            0x6a, 0x25, 0x6f, 0xbb, 0xd3, 0x72, 0x6c, 0x82, 0x3f, 0x99, 0xe5, 0xc5, 0x25, 0x2c,
+            0xfc, 0x36, 0x7d, 0x6e, 0xab, 0x0e, 0x6d, 0x1c, 0x18, 0x9d, 0x9b, 0x3a, 0x0a, 0x62,
+            0x68, 0xe9, 0xa7, 0x37, 0xf0,
+        ];
+        let (output, len) = encrypt(&plaintext, &associated_data);
+        assert_eq!(output[..len], expected);
+
+        let (output, tag) = decrypt(&expected[..17], &associated_data);
+        assert_eq!(output[..17], plaintext);
+        assert_eq!(tag, expected[17..]);
+    }
+
+    #[test]
+    fn ascon_hash256() {
+        let mut duplex = Duplex::<8, 12, 12>::new(State::new(
+            0x9b1e5494e934d681,
+            0x4bc3a01e333751d2,
+            0xae65396c6b34b81a,
+            0x3c7fd4a4d56a4db3,
+            0x1a5c464906c5976d,
+        ));
+        duplex.absorb_padded(b"some bytes");
+        duplex.permute_a();
+        let mut digest = [0u8; 32];
+        duplex.squeeze(&mut digest);
+        assert_eq!(
+            digest,
+            [
+                0xe9, 0x09, 0xc2, 0xf6, 0xda, 0x9c, 0xb3, 0x02, 0x84, 0x23, 0x26, 0x5c, 0x8f, 0x23,
+                0xfc, 0x2d, 0x26, 0xbf, 0xc0, 0xf3, 0xdb, 0x70, 0x46, 0x83, 0xef, 0x16, 0xb7, 0x87,
+                0xa9, 0x45, 0xed, 0x68
+            ]
+        );
+    }
+
+    #[test]
+    fn bytes() {
+        let mut duplex = Duplex::<16>::new(State::default());
+        duplex.xor_bytes(6, &[1, 2, 3, 4]);
+        assert_eq!(duplex.state()[0], 0x0201 << 48);
+        assert_eq!(duplex.state()[1], 0x0403);
+
+        let mut bytes = [0u8; 4];
+        duplex.read_bytes(6, &mut bytes);
+        assert_eq!(bytes, [1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,254 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Multiple states of Ascon's permutation processed in lockstep
+
+use core::ops::{Index, IndexMut};
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{STATE_WORDS, State};
+
+/// `N` states of Ascon's permutation processed in lockstep.
+///
+/// The states are stored lane-sliced, i.e., as 5 arrays holding the corresponding word of each
+/// state. The permutations operate on all states at once and are written such that the compiler is
+/// able to vectorise them, e.g., with SSE2, AVX2 or NEON. Indexing returns the words of all states
+/// at the given position.
+///
+/// With the `simd` feature on x86-64, multiples of 4 or 8 states are permuted with AVX2 or
+/// AVX-512, respectively, if supported by the CPU.
+///
+/// ```
+/// use ascon_core::{State, StateN};
+///
+/// let mut states = StateN::from([State::new(0, 1, 2, 3, 4), State::new(5, 6, 7, 8, 9)]);
+/// states.permute_12();
+///
+/// let mut state = State::new(5, 6, 7, 8, 9);
+/// state.permute_12();
+/// assert_eq!(states.get(1).as_bytes(), state.as_bytes());
+/// assert_eq!(states[0][1], state[0]);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct StateN<const N: usize> {
+    x: [[u64; N]; STATE_WORDS],
+}
+
+impl<const N: usize> Default for StateN<N> {
+    fn default() -> Self {
+        Self {
+            x: [[0; N]; STATE_WORDS],
+        }
+    }
+}
+
+/// Round constants of the permutation with 12 rounds
+const ROUND_CONSTANTS: [u64; 12] = [
+    0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69, 0x5a, 0x4b,
+];
+
+/// Ascon's round function applied to all states
+#[inline(always)]
+fn round<const N: usize>(x: &mut [[u64; N]; STATE_WORDS], c: u64) {
+    let [x0, x1, x2, x3, x4] = x;
+    for i in 0..N {
+        // S-box layer
+        let s0 = x0[i] ^ x4[i];
+        let s2 = x2[i] ^ x1[i] ^ c; // with round constant
+        let s4 = x4[i] ^ x3[i];
+
+        let t0 = s0 ^ (!x1[i] & s2);
+        let t1 = x1[i] ^ (!s2 & x3[i]);
+        let t2 = s2 ^ (!x3[i] & s4);
+        let t3 = x3[i] ^ (!s4 & s0);
+        let t4 = s4 ^ (!s0 & x1[i]);
+        let t1 = t1 ^ t0;
+        let t3 = t3 ^ t2;
+        let t0 = t0 ^ t4;
+
+        // linear layer
+        x0[i] = t0 ^ t0.rotate_right(19) ^ t0.rotate_right(28);
+        x1[i] = t1 ^ t1.rotate_right(61) ^ t1.rotate_right(39);
+        x2[i] = !(t2 ^ t2.rotate_right(1) ^ t2.rotate_right(6));
+        x3[i] = t3 ^ t3.rotate_right(10) ^ t3.rotate_right(17);
+        x4[i] = t4 ^ t4.rotate_right(7) ^ t4.rotate_right(41);
+    }
+}
+
+impl<const N: usize> StateN<N> {
+    /// Apply the rounds with the given round constants to all states.
+    #[inline(always)]
+    fn permute(&mut self, constants: &[u64]) {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        if crate::simd::permute(&mut self.x, constants) {
+            return;
+        }
+
+        for &c in constants {
+            round(&mut self.x, c);
+        }
+    }
+
+    /// Perform permutation with 12 rounds on all states.
+    pub fn permute_12(&mut self) {
+        self.permute(&ROUND_CONSTANTS);
+    }
+
+    /// Perform permutation with 8 rounds on all states.
+    pub fn permute_8(&mut self) {
+        self.permute(&ROUND_CONSTANTS[4..]);
+    }
+
+    #[cfg(feature = "permute_6")]
+    /// Perform permutation with 6 rounds on all states.
+    pub fn permute_6(&mut self) {
+        self.permute(&ROUND_CONSTANTS[6..]);
+    }
+
+    #[cfg(feature = "permute_1")]
+    /// Perform permutation with 1 round on all states.
+    pub fn permute_1(&mut self) {
+        self.permute(&ROUND_CONSTANTS[11..]);
+    }
+
+    /// Perform a given number (up to 12) of permutations on all states.
+    ///
+    /// Panics (in debug mode) if `rounds` is larger than 12.
+    pub fn permute_n(&mut self, rounds: usize) {
+        debug_assert!(rounds <= 12);
+
+        self.permute(&ROUND_CONSTANTS[12 - rounds..]);
+    }
+
+    /// Return the state at the given index.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> State {
+        State::new(
+            self.x[0][index],
+            self.x[1][index],
+            self.x[2][index],
+            self.x[3][index],
+            self.x[4][index],
+        )
+    }
+
+    /// Replace the state at the given index.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, state: &State) {
+        for (word, value) in self.x.iter_mut().zip(state.as_ref()) {
+            word[index] = *value;
+        }
+    }
+}
+
+impl<const N: usize> Index<usize> for StateN<N> {
+    type Output = [u64; N];
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.x[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for StateN<N> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.x[index]
+    }
+}
+
+impl<const N: usize> From<&[State; N]> for StateN<N> {
+    fn from(states: &[State; N]) -> Self {
+        let mut lanes = Self::default();
+        for (index, state) in states.iter().enumerate() {
+            lanes.set(index, state);
+        }
+        lanes
+    }
+}
+
+impl<const N: usize> From<[State; N]> for StateN<N> {
+    fn from(states: [State; N]) -> Self {
+        Self::from(&states)
+    }
+}
+
+impl<const N: usize> From<&StateN<N>> for [State; N] {
+    fn from(lanes: &StateN<N>) -> Self {
+        core::array::from_fn(|index| lanes.get(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn states<const N: usize>() -> [State; N] {
+        core::array::from_fn(|i| {
+            let i = i as u64;
+            State::new(
+                0x0123456789abcdef ^ i,
+                0xef0123456789abcdu64.rotate_left(i as u32),
+                0xcdef0123456789ab,
+                0xabcdef0123456789 ^ (i << 32),
+                0x89abcdef01234567,
+            )
+        })
+    }
+
+    fn check<const N: usize>(permute: impl Fn(&mut StateN<N>), permute_state: impl Fn(&mut State)) {
+        let mut expected = states::<N>();
+        let mut lanes = StateN::from(&expected);
+        permute(&mut lanes);
+        expected.iter_mut().for_each(permute_state);
+        let result: [State; N] = (&lanes).into();
+        for (result, expected) in result.iter().zip(expected) {
+            assert_eq!(result.as_ref(), expected.as_ref());
+        }
+    }
+
+    #[test]
+    fn matches_round() {
+        let expected = states::<3>();
+        let mut lanes = StateN::from(&expected);
+        round(&mut lanes.x, 0x1f);
+        for (index, state) in expected.iter().enumerate() {
+            let mut words = [0; STATE_WORDS];
+            words.copy_from_slice(state.as_ref());
+            assert_eq!(lanes.get(index).as_ref(), crate::round(words, 0x1f));
+        }
+    }
+
+    #[test]
+    fn permute() {
+        check::<1>(StateN::permute_12, State::permute_12);
+        check::<4>(StateN::permute_12, State::permute_12);
+        check::<5>(StateN::permute_8, State::permute_8);
+        #[cfg(feature = "permute_6")]
+        check::<2>(StateN::permute_6, State::permute_6);
+        #[cfg(feature = "permute_1")]
+        check::<2>(StateN::permute_1, State::permute_1);
+        for rounds in 0..=12 {
+            check::<8>(
+                |lanes| lanes.permute_n(rounds),
+                |state| state.permute_n(rounds),
+            );
+        }
+    }
+
+    #[test]
+    fn convert() {
+        let expected = states::<4>();
+        let mut lanes = StateN::from(&expected);
+        assert_eq!(lanes[3][2], expected[2][3]);
+
+        lanes.set(1, &State::new(1, 2, 3, 4, 5));
+        assert_eq!(lanes.get(1).as_ref(), [1, 2, 3, 4, 5]);
+        assert_eq!(lanes.get(2).as_ref(), expected[2].as_ref());
+    }
+}
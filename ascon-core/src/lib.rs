@@ -4,7 +4,8 @@
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc = include_str!("../README.md")]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "simd"), forbid(unsafe_code))]
+#![cfg_attr(feature = "simd", deny(unsafe_code))]
 #![warn(missing_docs)]
 
 use core::{
@@ -22,6 +23,17 @@ const STATE_SIZE: usize = WORD_SIZE * STATE_WORDS;
 #[cfg(feature = "zeroize")]
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+#[cfg(any(test, ascon_impl = "bit_interleaved"))]
+mod bit_interleaved;
+mod duplex;
+mod lanes;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+#[allow(unsafe_code)]
+mod simd;
+
+pub use duplex::Duplex;
+pub use lanes::StateN;
+
 /// Compute round constant
 #[inline(always)]
 const fn round_constant(round: u64) -> u64 {
@@ -29,19 +41,37 @@ const fn round_constant(round: u64) -> u64 {
 }
 
 #[cfg(not(ascon_impl = "no_unroll"))]
-macro_rules! apply_permutation {
-    ($state:expr, $rc:literal) => {
-        round($state, $rc)
+macro_rules! apply_rounds {
+    ($round:path, $state:expr, $rc:literal) => {
+        $round($state, $rc)
     };
-    ($state:expr, $rc:literal, $($rcs:literal),+) => {
-        apply_permutation!(round($state, $rc), $($rcs),+)
+    ($round:path, $state:expr, $rc:literal, $($rcs:literal),+) => {
+        apply_rounds!($round, $round($state, $rc), $($rcs),+)
     };
 }
 
 #[cfg(ascon_impl = "no_unroll")]
+macro_rules! apply_rounds {
+    ($round:path, $state:expr, $($rcs:literal),+) => {
+        [$($rcs),+].into_iter().fold($state, $round)
+    };
+}
+
+#[cfg(not(ascon_impl = "bit_interleaved"))]
 macro_rules! apply_permutation {
     ($state:expr, $($rcs:literal),+) => {
-        [$($rcs),+].into_iter().fold($state, round)
+        apply_rounds!(round, $state, $($rcs),+)
+    };
+}
+
+#[cfg(ascon_impl = "bit_interleaved")]
+macro_rules! apply_permutation {
+    ($state:expr, $($rcs:literal),+) => {
+        bit_interleaved::from_interleaved(apply_rounds!(
+            bit_interleaved::round,
+            bit_interleaved::to_interleaved($state),
+            $($rcs),+
+        ))
     };
 }
 
@@ -55,6 +85,7 @@ pub struct State {
 }
 
 /// Ascon's round function
+#[cfg_attr(ascon_impl = "bit_interleaved", allow(dead_code))]
 const fn round(x: [u64; STATE_WORDS], c: u64) -> [u64; STATE_WORDS] {
     // S-box layer
     let x0 = x[0] ^ x[4];
@@ -114,7 +145,7 @@ impl State {
     #[cfg(feature = "permute_1")]
     /// Perform permutation with 1 round
     pub fn permute_1(&mut self) {
-        self.x = round(self.x, 0x4b);
+        self.x = apply_permutation!(self.x, 0x4b);
     }
 
     /// Perform a given number (up to 12) of permutations
@@ -124,9 +155,20 @@ impl State {
         debug_assert!(rounds <= 12);
 
         let start = 12 - rounds;
-        self.x = (start..12).fold(self.x, |x, round_index| {
-            round(x, round_constant(round_index as u64))
-        });
+        #[cfg(not(ascon_impl = "bit_interleaved"))]
+        {
+            self.x = (start..12).fold(self.x, |x, round_index| {
+                round(x, round_constant(round_index as u64))
+            });
+        }
+        #[cfg(ascon_impl = "bit_interleaved")]
+        {
+            self.x = bit_interleaved::from_interleaved(
+                (start..12).fold(bit_interleaved::to_interleaved(self.x), |x, round_index| {
+                    bit_interleaved::round(x, round_constant(round_index as u64))
+                }),
+            );
+        }
     }
 
     /// Convert state to bytes.
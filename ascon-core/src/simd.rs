@@ -0,0 +1,83 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Vectorised permutations of multiple states on x86-64
+//!
+//! The backends process 4 (AVX2) or 8 (AVX-512) states per vector. They are selected at runtime
+//! based on the features supported by the CPU. If the number of states is not a multiple of the
+//! vector width or if the CPU lacks support, the portable implementation is used instead.
+
+use crate::STATE_WORDS;
+
+mod avx2;
+mod avx512;
+
+/// Apply the rounds with the given round constants to all states.
+///
+/// Returns `false` if no vectorised backend is available for `N` states on this CPU.
+#[inline(always)]
+pub(crate) fn permute<const N: usize>(x: &mut [[u64; N]; STATE_WORDS], constants: &[u64]) -> bool {
+    avx512::permute(x, constants) || avx2::permute(x, constants)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::State;
+
+    const ROUND_CONSTANTS: [u64; 12] = [
+        0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69, 0x5a, 0x4b,
+    ];
+
+    fn check<const N: usize>(permute: fn(&mut [[u64; N]; STATE_WORDS], &[u64]) -> bool) {
+        let mut x = [[0; N]; STATE_WORDS];
+        for (w, words) in x.iter_mut().enumerate() {
+            for (i, word) in words.iter_mut().enumerate() {
+                *word = 0x0123456789abcdefu64.rotate_left((8 * w + i) as u32) ^ i as u64;
+            }
+        }
+
+        for rounds in [1, 6, 8, 12] {
+            let mut y = x;
+            if !permute(&mut y, &ROUND_CONSTANTS[12 - rounds..]) {
+                // not supported by this CPU
+                return;
+            }
+            for i in 0..N {
+                let mut state = State::new(x[0][i], x[1][i], x[2][i], x[3][i], x[4][i]);
+                state.permute_n(rounds);
+                let result = State::new(y[0][i], y[1][i], y[2][i], y[3][i], y[4][i]);
+                assert_eq!(
+                    result.as_bytes(),
+                    state.as_bytes(),
+                    "{rounds} rounds, lane {i}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn avx2() {
+        check::<4>(avx2::permute);
+        check::<8>(avx2::permute);
+        check::<16>(avx2::permute);
+        assert!(!avx2::permute(&mut [[0; 3]; STATE_WORDS], &ROUND_CONSTANTS));
+    }
+
+    #[test]
+    fn avx512() {
+        check::<8>(avx512::permute);
+        check::<16>(avx512::permute);
+        assert!(!avx512::permute(
+            &mut [[0; 4]; STATE_WORDS],
+            &ROUND_CONSTANTS
+        ));
+    }
+
+    #[test]
+    fn dispatch() {
+        check::<4>(permute);
+        check::<8>(permute);
+        check::<24>(permute);
+    }
+}
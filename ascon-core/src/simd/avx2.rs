@@ -0,0 +1,116 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! AVX2 backend processing 4 states per vector
+
+use core::arch::x86_64::*;
+
+use crate::STATE_WORDS;
+
+cpufeatures::new!(avx2_cpuid, "avx2");
+
+/// Number of states per vector
+const LANES: usize = 4;
+
+/// Apply a bitwise operation to two vectors.
+macro_rules! op {
+    ($op:ident, $a:expr, $b:expr) => {{
+        let (a, b) = ($a, $b);
+        // SAFETY: only used in `permute_avx2`, whose caller checked that the CPU supports AVX2
+        unsafe { $op(a, b) }
+    }};
+}
+
+/// Rotate all words of the vector to the right.
+macro_rules! rotate_right {
+    ($x:ident, $n:literal) => {{
+        // SAFETY: only used in `permute_avx2`, whose caller checked that the CPU supports AVX2
+        let right = unsafe { _mm256_srli_epi64::<$n>($x) };
+        // SAFETY: only used in `permute_avx2`, whose caller checked that the CPU supports AVX2
+        let left = unsafe { _mm256_slli_epi64::<{ 64 - $n }>($x) };
+        op!(_mm256_or_si256, right, left)
+    }};
+}
+
+/// Apply the linear layer to all words of the vector.
+macro_rules! linear {
+    ($x:ident, $n:literal, $m:literal) => {
+        op!(
+            _mm256_xor_si256,
+            op!(_mm256_xor_si256, $x, rotate_right!($x, $n)),
+            rotate_right!($x, $m)
+        )
+    };
+}
+
+/// Apply the rounds to all states if `N` is a multiple of 4 and the CPU supports AVX2.
+pub(super) fn permute<const N: usize>(x: &mut [[u64; N]; STATE_WORDS], constants: &[u64]) -> bool {
+    if N % LANES != 0 || !avx2_cpuid::get() {
+        return false;
+    }
+
+    for offset in (0..N).step_by(LANES) {
+        // SAFETY: support for AVX2 has been checked above
+        unsafe { permute_avx2(x, offset, constants) };
+    }
+    true
+}
+
+/// Apply the rounds to the 4 states starting at `offset`.
+///
+/// # Safety
+///
+/// The CPU needs to support AVX2.
+#[target_feature(enable = "avx2")]
+// the arithmetic intrinsics are only unsafe to call before Rust 1.87
+#[rustversion::attr(since(1.87), allow(unused_unsafe))]
+unsafe fn permute_avx2<const N: usize>(
+    x: &mut [[u64; N]; STATE_WORDS],
+    offset: usize,
+    constants: &[u64],
+) {
+    // SAFETY: the caller checked that the CPU supports AVX2
+    let mut s = [unsafe { _mm256_setzero_si256() }; STATE_WORDS];
+    for (s, x) in s.iter_mut().zip(x.iter()) {
+        // SAFETY: the caller checked that the CPU supports AVX2, and slicing ensures that 4 words
+        // are available
+        *s = unsafe { _mm256_loadu_si256(x[offset..offset + LANES].as_ptr().cast()) };
+    }
+
+    // SAFETY: the caller checked that the CPU supports AVX2
+    let ones = unsafe { _mm256_set1_epi64x(-1) };
+    for &c in constants {
+        let [x0, x1, x2, x3, x4] = s;
+        // SAFETY: the caller checked that the CPU supports AVX2
+        let c = unsafe { _mm256_set1_epi64x(c as i64) };
+
+        // S-box layer
+        let s0 = op!(_mm256_xor_si256, x0, x4);
+        let s2 = op!(_mm256_xor_si256, op!(_mm256_xor_si256, x2, x1), c);
+        let s4 = op!(_mm256_xor_si256, x4, x3);
+
+        let t0 = op!(_mm256_xor_si256, s0, op!(_mm256_andnot_si256, x1, s2));
+        let t1 = op!(_mm256_xor_si256, x1, op!(_mm256_andnot_si256, s2, x3));
+        let t2 = op!(_mm256_xor_si256, s2, op!(_mm256_andnot_si256, x3, s4));
+        let t3 = op!(_mm256_xor_si256, x3, op!(_mm256_andnot_si256, s4, s0));
+        let t4 = op!(_mm256_xor_si256, s4, op!(_mm256_andnot_si256, s0, x1));
+        let t1 = op!(_mm256_xor_si256, t1, t0);
+        let t3 = op!(_mm256_xor_si256, t3, t2);
+        let t0 = op!(_mm256_xor_si256, t0, t4);
+
+        // linear layer
+        s = [
+            linear!(t0, 19, 28),
+            linear!(t1, 61, 39),
+            op!(_mm256_xor_si256, linear!(t2, 1, 6), ones),
+            linear!(t3, 10, 17),
+            linear!(t4, 7, 41),
+        ];
+    }
+
+    for (s, x) in s.into_iter().zip(x.iter_mut()) {
+        // SAFETY: the caller checked that the CPU supports AVX2, and slicing ensures that 4 words
+        // are available
+        unsafe { _mm256_storeu_si256(x[offset..offset + LANES].as_mut_ptr().cast(), s) };
+    }
+}
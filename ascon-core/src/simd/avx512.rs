@@ -0,0 +1,139 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! AVX-512 backend processing 8 states per vector
+//!
+//! The AVX-512 intrinsics are only available with Rust 1.89 or newer. With older compilers, this
+//! backend always reports that it is not available.
+
+pub(super) use imp::permute;
+
+#[rustversion::since(1.89)]
+mod imp {
+    use core::arch::x86_64::*;
+
+    use crate::STATE_WORDS;
+
+    cpufeatures::new!(avx512_cpuid, "avx512f");
+
+    /// Number of states per vector
+    const LANES: usize = 8;
+
+    /// Apply a bitwise operation to two vectors.
+    macro_rules! op {
+        ($op:ident, $a:expr, $b:expr) => {{
+            let (a, b) = ($a, $b);
+            // SAFETY: only used in `permute_avx512`, whose caller checked that the CPU supports
+            // AVX-512F
+            unsafe { $op(a, b) }
+        }};
+    }
+
+    /// Rotate all words of the vector to the right.
+    macro_rules! rotate_right {
+        ($x:ident, $n:literal) => {
+            // SAFETY: only used in `permute_avx512`, whose caller checked that the CPU supports
+            // AVX-512F
+            unsafe { _mm512_ror_epi64::<$n>($x) }
+        };
+    }
+
+    /// Apply the linear layer to all words of the vector.
+    macro_rules! linear {
+        ($x:ident, $n:literal, $m:literal) => {
+            op!(
+                _mm512_xor_si512,
+                op!(_mm512_xor_si512, $x, rotate_right!($x, $n)),
+                rotate_right!($x, $m)
+            )
+        };
+    }
+
+    /// Apply the rounds to all states if `N` is a multiple of 8 and the CPU supports AVX-512F.
+    pub(in super::super) fn permute<const N: usize>(
+        x: &mut [[u64; N]; STATE_WORDS],
+        constants: &[u64],
+    ) -> bool {
+        if N % LANES != 0 || !avx512_cpuid::get() {
+            return false;
+        }
+
+        for offset in (0..N).step_by(LANES) {
+            // SAFETY: support for AVX-512F has been checked above
+            unsafe { permute_avx512(x, offset, constants) };
+        }
+        true
+    }
+
+    /// Apply the rounds to the 8 states starting at `offset`.
+    ///
+    /// # Safety
+    ///
+    /// The CPU needs to support AVX-512F.
+    #[target_feature(enable = "avx512f")]
+    // the arithmetic intrinsics are only unsafe to call before Rust 1.87
+    #[rustversion::attr(since(1.87), allow(unused_unsafe))]
+    unsafe fn permute_avx512<const N: usize>(
+        x: &mut [[u64; N]; STATE_WORDS],
+        offset: usize,
+        constants: &[u64],
+    ) {
+        // SAFETY: the caller checked that the CPU supports AVX-512F
+        let mut s = [unsafe { _mm512_setzero_si512() }; STATE_WORDS];
+        for (s, x) in s.iter_mut().zip(x.iter()) {
+            // SAFETY: the caller checked that the CPU supports AVX-512F, and slicing ensures that
+            // 8 words are available
+            *s = unsafe { _mm512_loadu_si512(x[offset..offset + LANES].as_ptr().cast()) };
+        }
+
+        // SAFETY: the caller checked that the CPU supports AVX-512F
+        let ones = unsafe { _mm512_set1_epi64(-1) };
+        for &c in constants {
+            let [x0, x1, x2, x3, x4] = s;
+            // SAFETY: the caller checked that the CPU supports AVX-512F
+            let c = unsafe { _mm512_set1_epi64(c as i64) };
+
+            // S-box layer
+            let s0 = op!(_mm512_xor_si512, x0, x4);
+            let s2 = op!(_mm512_xor_si512, op!(_mm512_xor_si512, x2, x1), c);
+            let s4 = op!(_mm512_xor_si512, x4, x3);
+
+            let t0 = op!(_mm512_xor_si512, s0, op!(_mm512_andnot_si512, x1, s2));
+            let t1 = op!(_mm512_xor_si512, x1, op!(_mm512_andnot_si512, s2, x3));
+            let t2 = op!(_mm512_xor_si512, s2, op!(_mm512_andnot_si512, x3, s4));
+            let t3 = op!(_mm512_xor_si512, x3, op!(_mm512_andnot_si512, s4, s0));
+            let t4 = op!(_mm512_xor_si512, s4, op!(_mm512_andnot_si512, s0, x1));
+            let t1 = op!(_mm512_xor_si512, t1, t0);
+            let t3 = op!(_mm512_xor_si512, t3, t2);
+            let t0 = op!(_mm512_xor_si512, t0, t4);
+
+            // linear layer
+            s = [
+                linear!(t0, 19, 28),
+                linear!(t1, 61, 39),
+                op!(_mm512_xor_si512, linear!(t2, 1, 6), ones),
+                linear!(t3, 10, 17),
+                linear!(t4, 7, 41),
+            ];
+        }
+
+        for (s, x) in s.into_iter().zip(x.iter_mut()) {
+            // SAFETY: the caller checked that the CPU supports AVX-512F, and slicing ensures that
+            // 8 words are available
+            unsafe { _mm512_storeu_si512(x[offset..offset + LANES].as_mut_ptr().cast(), s) };
+        }
+    }
+}
+
+#[rustversion::before(1.89)]
+mod imp {
+    use crate::STATE_WORDS;
+
+    /// AVX-512 is not available with this compiler.
+    pub(in super::super) fn permute<const N: usize>(
+        _x: &mut [[u64; N]; STATE_WORDS],
+        _constants: &[u64],
+    ) -> bool {
+        false
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Ascon-Hash, Ascon-Hasha, Ascon-Xof and Ascon-Xofa from Ascon v1.2
+//!
+//! These variants absorb and squeeze in big-endian byte order and are not compatible with the
+//! hash functions and XOFs from SP 800-232. They are only provided for interoperability with
+//! existing deployments.
+
+use core::{fmt, marker::PhantomData};
+
+use ascon_core::State;
+#[cfg(feature = "zeroize")]
+use digest::zeroize::ZeroizeOnDrop;
+use digest::{
+    HashMarker, Output, OutputSizeUser, Reset,
+    block_api::{
+        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, Eager, ExtendableOutputCore,
+        FixedOutputCore, UpdateCore, XofReaderCore,
+    },
+    common::hazmat::{DeserializeStateError, SerializableState, SerializedState},
+    consts::{U8, U32, U40},
+};
+
+use crate::{HashCore, HashParameters};
+
+/// Parameters for Ascon-Hash (v1.2).
+#[derive(Clone, Debug)]
+struct ParametersHash;
+
+impl HashParameters for ParametersHash {
+    const IV0: u64 = 0xee9398aadb67f03d;
+    const IV1: u64 = 0x8bb21831c60f1002;
+    const IV2: u64 = 0xb48a92db98d5da62;
+    const IV3: u64 = 0x43189921b8f8e3e8;
+    const IV4: u64 = 0x348fa5c9d525e140;
+    const BIG_ENDIAN: bool = true;
+}
+
+/// Parameters for Ascon-Hasha (v1.2).
+#[derive(Clone, Debug)]
+struct ParametersHasha;
+
+impl HashParameters for ParametersHasha {
+    const IV0: u64 = 0x01470194fc6528a6;
+    const IV1: u64 = 0x738ec38ac0adffa7;
+    const IV2: u64 = 0x2ec8e3296c76384c;
+    const IV3: u64 = 0xd6f6a54d7f52377d;
+    const IV4: u64 = 0xa13c42a223be8d87;
+    const ROUNDS: usize = 8;
+    const BIG_ENDIAN: bool = true;
+}
+
+/// Parameters for Ascon-Xof (v1.2).
+#[derive(Clone, Debug)]
+struct ParametersXof;
+
+impl HashParameters for ParametersXof {
+    const IV0: u64 = 0xb57e273b814cd416;
+    const IV1: u64 = 0x2b51042562ae2420;
+    const IV2: u64 = 0x66a3a7768ddf2218;
+    const IV3: u64 = 0x5aad0a7a8153650c;
+    const IV4: u64 = 0x4f3e0e32539493b6;
+    const BIG_ENDIAN: bool = true;
+}
+
+/// Parameters for Ascon-Xofa (v1.2).
+#[derive(Clone, Debug)]
+struct ParametersXofa;
+
+impl HashParameters for ParametersXofa {
+    const IV0: u64 = 0x44906568b77b9832;
+    const IV1: u64 = 0xcd8d6cae53455532;
+    const IV2: u64 = 0xf7b5212756422129;
+    const IV3: u64 = 0x246885e1de0d225b;
+    const IV4: u64 = 0xa8cb5ce33449973f;
+    const ROUNDS: usize = 8;
+    const BIG_ENDIAN: bool = true;
+}
+
+/// Implement the traits shared by all hash and XOF cores.
+macro_rules! impl_core {
+    ($core:ident, $name:literal) => {
+        #[cfg(feature = "zeroize")]
+        impl ZeroizeOnDrop for $core {}
+
+        impl HashMarker for $core {}
+
+        impl BlockSizeUser for $core {
+            type BlockSize = U8;
+        }
+
+        impl BufferKindUser for $core {
+            type BufferKind = Eager;
+        }
+
+        impl UpdateCore for $core {
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                for block in blocks {
+                    self.state.absorb_block(block.as_ref());
+                }
+            }
+        }
+
+        impl Reset for $core {
+            fn reset(&mut self) {
+                *self = Default::default();
+            }
+        }
+
+        impl AlgorithmName for $core {
+            fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str($name)
+            }
+        }
+
+        impl SerializableState for $core {
+            type SerializedStateSize = U40;
+
+            fn serialize(&self) -> SerializedState<Self> {
+                self.state.state.as_bytes().into()
+            }
+
+            fn deserialize(
+                serialized_state: &SerializedState<Self>,
+            ) -> Result<Self, DeserializeStateError> {
+                let state = State::from(&serialized_state.0);
+                Ok(Self {
+                    state: HashCore {
+                        state,
+                        phantom: PhantomData,
+                    },
+                })
+            }
+        }
+    };
+}
+
+/// Implement a hash core with 256 bit output.
+macro_rules! impl_hash_core {
+    ($core:ident, $parameters:ty, $name:literal) => {
+        #[doc = concat!($name, " hash implementation")]
+        #[derive(Clone, Debug, Default)]
+        pub struct $core {
+            state: HashCore<$parameters>,
+        }
+
+        impl_core!($core, $name);
+
+        impl OutputSizeUser for $core {
+            type OutputSize = U32;
+        }
+
+        impl FixedOutputCore for $core {
+            fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+                debug_assert!(buffer.get_pos() < 8);
+                self.state
+                    .absorb_last_block(&buffer.get_data()[..buffer.get_pos()]);
+                self.state.squeeze(out);
+            }
+        }
+    };
+}
+
+/// Implement a XOF core and its reader.
+macro_rules! impl_xof_core {
+    ($core:ident, $reader:ident, $parameters:ty, $name:literal) => {
+        #[doc = concat!($name, " implementation")]
+        #[derive(Clone, Debug, Default)]
+        pub struct $core {
+            state: HashCore<$parameters>,
+        }
+
+        impl_core!($core, $name);
+
+        #[doc = concat!("Reader for ", $name, " output")]
+        #[derive(Clone, Debug)]
+        pub struct $reader {
+            hasher: HashCore<$parameters>,
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl ZeroizeOnDrop for $reader {}
+
+        impl BlockSizeUser for $reader {
+            type BlockSize = U8;
+        }
+
+        impl XofReaderCore for $reader {
+            fn read_block(&mut self) -> Block<Self> {
+                self.hasher.squeeze_block().into()
+            }
+        }
+
+        impl ExtendableOutputCore for $core {
+            type ReaderCore = $reader;
+
+            fn finalize_xof_core(&mut self, buffer: &mut Buffer<Self>) -> Self::ReaderCore {
+                debug_assert!(buffer.get_pos() < 8);
+                self.state
+                    .absorb_last_block(&buffer.get_data()[..buffer.get_pos()]);
+                Self::ReaderCore {
+                    hasher: self.state.clone(),
+                }
+            }
+        }
+    };
+}
+
+impl_hash_core!(AsconHashCore, ParametersHash, "Ascon-Hash");
+impl_hash_core!(AsconHashaCore, ParametersHasha, "Ascon-Hasha");
+impl_xof_core!(AsconXofCore, AsconXofReaderCore, ParametersXof, "Ascon-Xof");
+impl_xof_core!(
+    AsconXofaCore,
+    AsconXofaReaderCore,
+    ParametersXofa,
+    "Ascon-Xofa"
+);
+
+digest::buffer_fixed!(
+    /// Ascon-Hash (v1.2)
+    pub struct AsconHash(AsconHashCore);
+    impl: FixedHashTraits;
+);
+
+digest::buffer_fixed!(
+    /// Ascon-Hasha (v1.2)
+    pub struct AsconHasha(AsconHashaCore);
+    impl: FixedHashTraits;
+);
+
+digest::buffer_xof!(
+    /// Ascon-Xof (v1.2) hasher.
+    pub struct AsconXof(AsconXofCore);
+    impl: XofHasherTraits;
+    /// Ascon-Xof (v1.2) reader.
+    pub struct AsconXofReader(AsconXofReaderCore);
+    impl: XofReaderTraits;
+);
+
+digest::buffer_xof!(
+    /// Ascon-Xofa (v1.2) hasher.
+    pub struct AsconXofa(AsconXofaCore);
+    impl: XofHasherTraits;
+    /// Ascon-Xofa (v1.2) reader.
+    pub struct AsconXofaReader(AsconXofaReaderCore);
+    impl: XofReaderTraits;
+);
@@ -29,13 +29,42 @@
 //! reader.read(&mut dst);
 //! assert_eq!(&dst, b"\x8c\x7d\xd1\x14\xa0");
 //! ```
+//!
+//! ## Usage (CXOF)
+//!
+//! ```
+//! use ascon_hash::{AsconCxof128, CustomizedInit, ExtendableOutput, Update, XofReader};
+//!
+//! let mut xof = AsconCxof128::new_customized(b"my customization");
+//! xof.update(b"some bytes");
+//! let mut reader = xof.finalize_xof();
+//! let mut dst = [0u8; 5];
+//! reader.read(&mut dst);
+//! assert_eq!(&dst, b"\x2c\x11\xb6\xe6\x47");
+//! ```
+//!
+//! ## Ascon v1.2
+//!
+//! Ascon-Hash, Ascon-Hasha, Ascon-Xof and Ascon-Xofa from version 1.2 of the Ascon
+//! specification are available via the `legacy` feature as [`AsconHash`], [`AsconHasha`],
+//! [`AsconXof`] and [`AsconXofa`]. They produce different outputs than the SP 800-232 variants
+//! and should only be used to interoperate with existing deployments.
+//!
+//! ```
+//! # #[cfg(feature = "legacy")] {
+//! use ascon_hash::{AsconHash, Digest};
+//!
+//! let digest = AsconHash::digest(b"");
+//! assert_eq!(&digest[..4], b"\x73\x46\xbc\x14");
+//! # }
+//! ```
 
 use core::{fmt, marker::PhantomData};
 
 use ascon_core::State;
 #[cfg(feature = "zeroize")]
 use digest::zeroize::ZeroizeOnDrop;
-pub use digest::{self, Digest, ExtendableOutput, Reset, Update, XofReader};
+pub use digest::{self, CustomizedInit, Digest, ExtendableOutput, Reset, Update, XofReader};
 use digest::{
     CollisionResistance, HashMarker, Output, OutputSizeUser,
     block_api::{
@@ -43,9 +72,18 @@ use digest::{
         FixedOutputCore, UpdateCore, XofReaderCore,
     },
     common::hazmat::{DeserializeStateError, SerializableState, SerializedState},
-    consts::{U8, U16, U32, U40},
+    consts::{U8, U16, U32, U40, U80},
 };
 
+mod batch;
+#[cfg(feature = "legacy")]
+pub mod legacy;
+#[cfg(feature = "legacy")]
+pub use legacy::{AsconHash, AsconHasha, AsconXof, AsconXofReader, AsconXofa, AsconXofaReader};
+
+/// Maximal size of customization strings (in bytes) supported by Ascon-CXOF128.
+pub const MAX_CUSTOMIZATION_SIZE: usize = 256;
+
 /// Produce mask for padding.
 #[inline(always)]
 const fn pad(n: usize) -> u64 {
@@ -66,6 +104,10 @@ trait HashParameters {
     const IV3: u64;
     /// Part of the IV.
     const IV4: u64;
+    /// Number of rounds of the permutation used between blocks (8 or 12).
+    const ROUNDS: usize = 12;
+    /// Whether words are loaded and stored in big-endian byte order as in Ascon v1.2.
+    const BIG_ENDIAN: bool = false;
 }
 
 /// Parameters for Ascon-Hash256.
@@ -92,6 +134,18 @@ impl HashParameters for ParametersXof {
     const IV4: u64 = 0xe0547524db6f0bde;
 }
 
+/// Parameters for Ascon-CXOF128
+#[derive(Clone, Debug)]
+struct ParametersCxof;
+
+impl HashParameters for ParametersCxof {
+    const IV0: u64 = 0x675527c2a0e8de03;
+    const IV1: u64 = 0x43d12d7dc0377bbc;
+    const IV2: u64 = 0xe9901dec426e81b5;
+    const IV3: u64 = 0x2ab14907720780b6;
+    const IV4: u64 = 0x8f3f1d02d432bc46;
+}
+
 #[derive(Clone, Debug)]
 struct HashCore<P: HashParameters> {
     state: State,
@@ -102,8 +156,35 @@ struct HashCore<P: HashParameters> {
 impl<P: HashParameters> ZeroizeOnDrop for HashCore<P> {}
 
 impl<P: HashParameters> HashCore<P> {
+    #[inline(always)]
+    fn load(block: [u8; 8]) -> u64 {
+        if P::BIG_ENDIAN {
+            u64::from_be_bytes(block)
+        } else {
+            u64::from_le_bytes(block)
+        }
+    }
+
+    #[inline(always)]
+    fn store(word: u64) -> [u8; 8] {
+        if P::BIG_ENDIAN {
+            u64::to_be_bytes(word)
+        } else {
+            u64::to_le_bytes(word)
+        }
+    }
+
+    #[inline(always)]
+    fn pad(n: usize) -> u64 {
+        if P::BIG_ENDIAN {
+            0x80_u64 << (56 - 8 * n)
+        } else {
+            pad(n)
+        }
+    }
+
     fn absorb_block(&mut self, block: &[u8; 8]) {
-        self.state[0] ^= u64::from_le_bytes(*block);
+        self.state[0] ^= Self::load(*block);
         self.permute_state();
     }
 
@@ -114,34 +195,52 @@ impl<P: HashParameters> HashCore<P> {
         if len > 0 {
             let mut tmp = [0u8; 8];
             tmp[0..len].copy_from_slice(block);
-            self.state[0] ^= u64::from_le_bytes(tmp);
+            self.state[0] ^= Self::load(tmp);
         }
-        self.state[0] ^= pad(len);
+        self.state[0] ^= Self::pad(len);
         self.state.permute_12();
     }
 
+    // for customized XOFs
+    fn absorb_customization(&mut self, customization: &[u8]) {
+        debug_assert!(customization.len() <= MAX_CUSTOMIZATION_SIZE);
+
+        self.absorb_block(&u64::to_le_bytes(8 * customization.len() as u64));
+        let mut blocks = customization.chunks_exact(8);
+        for block in blocks.by_ref() {
+            // Soundness: chunks_exact only returns blocks of 8 bytes
+            self.absorb_block(block.try_into().unwrap());
+        }
+        self.absorb_last_block(blocks.remainder());
+    }
+
     // for fixed-sized output
     fn squeeze(&mut self, mut block: &mut [u8]) {
         debug_assert_eq!(block.len() % 8, 0);
 
         while block.len() > 8 {
-            block[..8].copy_from_slice(&u64::to_le_bytes(self.state[0]));
+            block[..8].copy_from_slice(&Self::store(self.state[0]));
             self.permute_state();
             block = &mut block[8..];
         }
-        block[..8].copy_from_slice(&u64::to_le_bytes(self.state[0]));
+        block[..8].copy_from_slice(&Self::store(self.state[0]));
     }
 
     // for XOF output
     fn squeeze_block(&mut self) -> [u8; 8] {
-        let ret = u64::to_le_bytes(self.state[0]);
+        let ret = Self::store(self.state[0]);
         self.permute_state();
         ret
     }
 
     #[inline(always)]
     fn permute_state(&mut self) {
-        self.state.permute_12();
+        if P::ROUNDS == 8 {
+            self.state.permute_8();
+        } else {
+            debug_assert_eq!(P::ROUNDS, 12);
+            self.state.permute_12();
+        }
     }
 }
 
@@ -317,6 +416,131 @@ impl SerializableState for AsconXofCore {
     }
 }
 
+/// Ascon CXOF
+#[derive(Clone, Debug)]
+pub struct AsconCxofCore {
+    state: HashCore<ParametersCxof>,
+    // state after absorbing the customization string
+    initial_state: HashCore<ParametersCxof>,
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for AsconCxofCore {}
+
+impl HashMarker for AsconCxofCore {}
+
+impl BlockSizeUser for AsconCxofCore {
+    type BlockSize = U8;
+}
+
+impl BufferKindUser for AsconCxofCore {
+    type BufferKind = Eager;
+}
+
+impl CustomizedInit for AsconCxofCore {
+    /// Create new instance with the given customization string.
+    ///
+    /// Panics if the customization string is longer than [`MAX_CUSTOMIZATION_SIZE`] bytes.
+    fn new_customized(customization: &[u8]) -> Self {
+        assert!(
+            customization.len() <= MAX_CUSTOMIZATION_SIZE,
+            "customization string too long"
+        );
+
+        let mut state = HashCore::default();
+        state.absorb_customization(customization);
+        Self {
+            initial_state: state.clone(),
+            state,
+        }
+    }
+}
+
+impl Default for AsconCxofCore {
+    fn default() -> Self {
+        Self::new_customized(&[])
+    }
+}
+
+impl UpdateCore for AsconCxofCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        for block in blocks {
+            self.state.absorb_block(block.as_ref());
+        }
+    }
+}
+
+/// Reader for CXOF output
+#[derive(Clone, Debug)]
+pub struct AsconCxofReaderCore {
+    hasher: HashCore<ParametersCxof>,
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for AsconCxofReaderCore {}
+
+impl BlockSizeUser for AsconCxofReaderCore {
+    type BlockSize = U8;
+}
+
+impl XofReaderCore for AsconCxofReaderCore {
+    fn read_block(&mut self) -> Block<Self> {
+        self.hasher.squeeze_block().into()
+    }
+}
+
+impl ExtendableOutputCore for AsconCxofCore {
+    type ReaderCore = AsconCxofReaderCore;
+
+    fn finalize_xof_core(&mut self, buffer: &mut Buffer<Self>) -> Self::ReaderCore {
+        debug_assert!(buffer.get_pos() < 8);
+        self.state
+            .absorb_last_block(&buffer.get_data()[..buffer.get_pos()]);
+        Self::ReaderCore {
+            hasher: self.state.clone(),
+        }
+    }
+}
+
+impl Reset for AsconCxofCore {
+    fn reset(&mut self) {
+        self.state = self.initial_state.clone();
+    }
+}
+
+impl AlgorithmName for AsconCxofCore {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Ascon-CXOF128")
+    }
+}
+
+impl SerializableState for AsconCxofCore {
+    type SerializedStateSize = U80;
+
+    fn serialize(&self) -> SerializedState<Self> {
+        let mut serialized_state = SerializedState::<Self>::default();
+        serialized_state[..40].copy_from_slice(&self.state.state.as_bytes());
+        serialized_state[40..].copy_from_slice(&self.initial_state.state.as_bytes());
+        serialized_state
+    }
+
+    fn deserialize(
+        serialized_state: &SerializedState<Self>,
+    ) -> Result<Self, DeserializeStateError> {
+        let (state, initial_state) = serialized_state.split_ref::<U40>();
+        Ok(Self {
+            state: HashCore {
+                state: State::from(&state.0),
+                phantom: PhantomData,
+            },
+            initial_state: HashCore {
+                state: State::from(&initial_state.0),
+                phantom: PhantomData,
+            },
+        })
+    }
+}
+
 digest::buffer_fixed!(
     /// Ascon-Hash256
     pub struct AsconHash256(AsconCore);
@@ -336,3 +560,17 @@ impl CollisionResistance for AsconXof128 {
     // https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-232.pdf#table.caption.24
     type CollisionResistance = U16;
 }
+
+digest::buffer_xof!(
+    /// Ascon-CXOF128 hasher.
+    pub struct AsconCxof128(AsconCxofCore);
+    impl: XofHasherTraits CustomizedInit;
+    /// Ascon-CXOF128 reader.
+    pub struct AsconCxof128Reader(AsconCxofReaderCore);
+    impl: XofReaderTraits;
+);
+
+impl CollisionResistance for AsconCxof128 {
+    // https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-232.pdf#table.caption.24
+    type CollisionResistance = U16;
+}
@@ -0,0 +1,36 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use ascon_hash::{AsconHash256, Digest};
+
+fn check<const N: usize>(offset: usize) {
+    let data: Vec<u8> = (0..=255).collect();
+    let messages: [&[u8]; N] = core::array::from_fn(|i| &data[..(offset + 7 * i) % 80]);
+    let digests = AsconHash256::digest_batch(&messages);
+    for (message, digest) in messages.iter().zip(digests) {
+        assert_eq!(digest, AsconHash256::digest(message), "{}", message.len());
+    }
+}
+
+#[test]
+fn digest_batch() {
+    check::<0>(0);
+    check::<1>(0);
+    check::<1>(17);
+    check::<4>(0);
+    check::<4>(3);
+    check::<5>(8);
+    check::<8>(1);
+    check::<16>(5);
+}
+
+#[test]
+fn digest_batch_equal_lengths() {
+    let messages = [b"abcdefghijklmnop".as_slice(); 8];
+    let digests = AsconHash256::digest_batch(&messages);
+    assert!(
+        digests
+            .iter()
+            .all(|digest| *digest == AsconHash256::digest(messages[0]))
+    );
+}
@@ -6,20 +6,25 @@ use std::include_str;
 
 use spectral::prelude::{OrderedAssertions, asserting};
 
-use ascon_hash::{AsconHash256, AsconXof128, Digest, ExtendableOutput, Reset, XofReader};
+use ascon_hash::{
+    AsconCxof128, AsconHash256, AsconXof128, CustomizedInit, Digest, ExtendableOutput, Reset,
+    XofReader,
+};
 
 #[derive(Debug)]
 struct TestVector {
     count: u32,
     message: Vec<u8>,
+    customization: Vec<u8>,
     digest: Vec<u8>,
 }
 
 impl TestVector {
-    fn new(count: &str, message: &str, digest: &str) -> Self {
+    fn new(count: &str, message: &str, customization: &str, digest: &str) -> Self {
         Self {
             count: count.parse().unwrap(),
             message: hex::decode(message).unwrap(),
+            customization: hex::decode(customization).unwrap(),
             digest: hex::decode(digest).unwrap(),
         }
     }
@@ -60,6 +65,28 @@ fn run_tv_xof<X: ExtendableOutput + Default>(tv: TestVector) {
         .is_not_equal_to(&tv.digest);
 }
 
+fn run_tv_cxof<X: ExtendableOutput + CustomizedInit + Reset + Clone>(tv: TestVector) {
+    let mut hasher = X::new_customized(&tv.customization);
+    hasher.update(&tv.message);
+    let mut hasher2 = hasher.clone();
+    let mut reader = hasher.finalize_xof();
+    let mut digest = vec![0u8; tv.digest.len()];
+    reader.read(digest.as_mut_slice());
+    asserting(format!("Test Vector {}: CXOF", tv.count).as_str())
+        .that(&digest)
+        .is_equal_to(&tv.digest);
+
+    Reset::reset(&mut hasher2);
+    for b in tv.message {
+        hasher2.update(&[b]);
+    }
+    let mut reader = hasher2.finalize_xof();
+    reader.read(digest.as_mut_slice());
+    asserting(format!("Test Vector {}: After reset", tv.count).as_str())
+        .that(&digest)
+        .is_equal_to(&tv.digest);
+}
+
 fn parse_tvs(tvs: &str) -> Vec<TestVector> {
     let mut fields: HashMap<&str, &str> = HashMap::new();
     let mut ret = Vec::new();
@@ -69,6 +96,7 @@ fn parse_tvs(tvs: &str) -> Vec<TestVector> {
             ret.push(TestVector::new(
                 fields["Count"],
                 fields["Msg"],
+                fields.get("Z").unwrap_or(&""),
                 fields["MD"],
             ));
             fields.clear();
@@ -100,3 +128,47 @@ fn test_vectors_asconxof() {
         run_tv_xof::<AsconXof128>(tv);
     }
 }
+
+#[test]
+fn test_vectors_asconcxof() {
+    let tvs = parse_tvs(include_str!("data/asconcxof.txt"));
+    for tv in tvs {
+        run_tv_cxof::<AsconCxof128>(tv);
+    }
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn test_vectors_asconhashv12() {
+    let tvs = parse_tvs(include_str!("data/asconhashv12.txt"));
+    for tv in tvs {
+        run_tv::<ascon_hash::AsconHash>(tv);
+    }
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn test_vectors_asconhashav12() {
+    let tvs = parse_tvs(include_str!("data/asconhashav12.txt"));
+    for tv in tvs {
+        run_tv::<ascon_hash::AsconHasha>(tv);
+    }
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn test_vectors_asconxofv12() {
+    let tvs = parse_tvs(include_str!("data/asconxofv12.txt"));
+    for tv in tvs {
+        run_tv_xof::<ascon_hash::AsconXof>(tv);
+    }
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn test_vectors_asconxofav12() {
+    let tvs = parse_tvs(include_str!("data/asconxofav12.txt"));
+    for tv in tvs {
+        run_tv_xof::<ascon_hash::AsconXofa>(tv);
+    }
+}
@@ -0,0 +1,420 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc = include_str!("../README.md")]
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+//! ## Usage (MAC)
+//!
+//! ```
+//! use ascon_mac::{AsconMac, KeyInit, Mac};
+//!
+//! let mut mac = AsconMac::new_from_slice(b"very secret key.").unwrap();
+//! mac.update(b"some bytes");
+//! let tag = mac.finalize().into_bytes();
+//! assert_eq!(&tag[..], b"\x75\xbb\x77\xe1\x49\x19\x7e\xbf\x4f\xd3\x1a\x4e\xe1\x8e\x5f\x38");
+//!
+//! // verification is performed in constant time
+//! let mut mac = AsconMac::new_from_slice(b"very secret key.").unwrap();
+//! mac.update(b"some bytes");
+//! assert!(mac.verify(&tag).is_ok());
+//! ```
+//!
+//! ## Usage (PRF)
+//!
+//! ```
+//! use ascon_mac::{AsconPrf, ExtendableOutput, KeyInit, Update, XofReader};
+//!
+//! let mut prf = AsconPrf::new_from_slice(b"very secret key.").unwrap();
+//! prf.update(b"some bytes");
+//! let mut reader = prf.finalize_xof();
+//! let mut dst = [0u8; 8];
+//! reader.read(&mut dst);
+//! assert_eq!(&dst, b"\x64\x4c\x39\xc9\xd7\x5c\x31\xe6");
+//! ```
+//!
+//! ## Usage (short-input PRF)
+//!
+//! ```
+//! use ascon_mac::{AsconPrfShort, KeyInit};
+//!
+//! let prf = AsconPrfShort::new_from_slice(b"very secret key.").unwrap();
+//! let mut tag = [0u8; 16];
+//! prf.compute(b"short", &mut tag).unwrap();
+//! assert_eq!(&tag, b"\x73\x51\xf0\x96\x45\x32\x41\x14\xb7\xfd\xb0\x0c\xba\x93\x3a\x86");
+//! assert!(prf.verify(b"short", &tag).is_ok());
+//! ```
+
+use core::fmt;
+
+use ascon_core::State;
+#[cfg(feature = "zeroize")]
+use digest::zeroize::ZeroizeOnDrop;
+pub use digest::{
+    self, ExtendableOutput, FixedOutput, InvalidLength, Key, KeyInit, Mac, MacError, Reset, Update,
+    XofReader,
+};
+use digest::{
+    MacMarker, Output, OutputSizeUser,
+    block_api::{
+        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreProxy, Eager,
+        ExtendableOutputCore, FixedOutputCore, UpdateCore, XofReaderCore,
+    },
+    common::KeySizeUser,
+    consts::{U16, U32},
+};
+use subtle::ConstantTimeEq;
+
+/// Maximal input size (in bytes) supported by Ascon-PrfShort.
+pub const MAX_PRF_SHORT_INPUT_SIZE: usize = 16;
+/// Maximal output size (in bytes) supported by Ascon-PrfShort.
+pub const MAX_PRF_SHORT_OUTPUT_SIZE: usize = 16;
+
+/// Produce mask for padding.
+#[inline(always)]
+const fn pad(n: usize) -> u64 {
+    0x01_u64 << (8 * n)
+}
+
+// Helper functions to convert &[u8] to u64.
+
+#[inline]
+fn u64_from_bytes(input: &[u8]) -> u64 {
+    // Soundness: function is always called with slices of the correct size
+    u64::from_le_bytes(input.try_into().unwrap())
+}
+
+#[inline]
+fn u64_from_bytes_partial(input: &[u8]) -> u64 {
+    let mut tmp = [0u8; 8];
+    tmp[0..input.len()].copy_from_slice(input);
+    u64::from_le_bytes(tmp)
+}
+
+/// Initialization vector of Ascon-Mac
+const IV_MAC: u64 = 0x00000080008c8080;
+/// Initialization vector of Ascon-Prf
+const IV_PRF: u64 = 0x00000000008c8080;
+
+/// Shared sponge of Ascon-Mac and Ascon-Prf
+///
+/// Input is absorbed with a rate of 32 bytes and output is squeezed with a rate of 16 bytes.
+#[derive(Clone, Debug)]
+struct PrfCore {
+    state: State,
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for PrfCore {}
+
+impl PrfCore {
+    fn new(iv: u64, key: &Key<AsconMacCore>) -> Self {
+        let mut state = State::new(
+            iv,
+            u64_from_bytes(&key[..8]),
+            u64_from_bytes(&key[8..]),
+            0,
+            0,
+        );
+        state.permute_12();
+        Self { state }
+    }
+
+    fn absorb_block(&mut self, block: &[u8; 32]) {
+        for (i, word) in block.chunks_exact(8).enumerate() {
+            self.state[i] ^= u64_from_bytes(word);
+        }
+        self.state.permute_12();
+    }
+
+    fn absorb_last_block(&mut self, block: &[u8]) {
+        debug_assert!(block.len() < 32);
+
+        let mut words = block.chunks_exact(8);
+        let mut idx = 0;
+        for word in words.by_ref() {
+            self.state[idx] ^= u64_from_bytes(word);
+            idx += 1;
+        }
+        let last_word = words.remainder();
+        if !last_word.is_empty() {
+            self.state[idx] ^= u64_from_bytes_partial(last_word);
+        }
+        self.state[idx] ^= pad(last_word.len());
+        // domain separation of the last block
+        self.state[4] ^= 0x8000000000000000;
+        self.state.permute_12();
+    }
+
+    fn squeeze_block(&mut self) -> [u8; 16] {
+        let mut block = [0u8; 16];
+        block[..8].copy_from_slice(&u64::to_le_bytes(self.state[0]));
+        block[8..].copy_from_slice(&u64::to_le_bytes(self.state[1]));
+        self.state.permute_12();
+        block
+    }
+}
+
+/// Ascon-Mac implementation
+#[derive(Clone, Debug)]
+pub struct AsconMacCore {
+    state: PrfCore,
+    // state after absorbing the key
+    initial_state: PrfCore,
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for AsconMacCore {}
+
+impl MacMarker for AsconMacCore {}
+
+impl KeySizeUser for AsconMacCore {
+    type KeySize = U16;
+}
+
+impl KeyInit for AsconMacCore {
+    fn new(key: &Key<Self>) -> Self {
+        let state = PrfCore::new(IV_MAC, key);
+        Self {
+            initial_state: state.clone(),
+            state,
+        }
+    }
+}
+
+impl BlockSizeUser for AsconMacCore {
+    type BlockSize = U32;
+}
+
+impl BufferKindUser for AsconMacCore {
+    type BufferKind = Eager;
+}
+
+impl OutputSizeUser for AsconMacCore {
+    type OutputSize = U16;
+}
+
+impl UpdateCore for AsconMacCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        for block in blocks {
+            self.state.absorb_block(block.as_ref());
+        }
+    }
+}
+
+impl FixedOutputCore for AsconMacCore {
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        debug_assert!(buffer.get_pos() < 32);
+        self.state
+            .absorb_last_block(&buffer.get_data()[..buffer.get_pos()]);
+        out.copy_from_slice(&self.state.squeeze_block());
+    }
+}
+
+impl Reset for AsconMacCore {
+    fn reset(&mut self) {
+        self.state = self.initial_state.clone();
+    }
+}
+
+impl AlgorithmName for AsconMacCore {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Ascon-Mac")
+    }
+}
+
+/// Ascon-Prf implementation
+#[derive(Clone, Debug)]
+pub struct AsconPrfCore {
+    state: PrfCore,
+    // state after absorbing the key
+    initial_state: PrfCore,
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for AsconPrfCore {}
+
+impl KeySizeUser for AsconPrfCore {
+    type KeySize = U16;
+}
+
+impl KeyInit for AsconPrfCore {
+    fn new(key: &Key<Self>) -> Self {
+        let state = PrfCore::new(IV_PRF, key);
+        Self {
+            initial_state: state.clone(),
+            state,
+        }
+    }
+}
+
+impl BlockSizeUser for AsconPrfCore {
+    type BlockSize = U32;
+}
+
+impl BufferKindUser for AsconPrfCore {
+    type BufferKind = Eager;
+}
+
+impl UpdateCore for AsconPrfCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        for block in blocks {
+            self.state.absorb_block(block.as_ref());
+        }
+    }
+}
+
+/// Reader for Ascon-Prf output
+#[derive(Clone, Debug)]
+pub struct AsconPrfReaderCore {
+    state: PrfCore,
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for AsconPrfReaderCore {}
+
+impl BlockSizeUser for AsconPrfReaderCore {
+    type BlockSize = U16;
+}
+
+impl XofReaderCore for AsconPrfReaderCore {
+    fn read_block(&mut self) -> Block<Self> {
+        self.state.squeeze_block().into()
+    }
+}
+
+impl ExtendableOutputCore for AsconPrfCore {
+    type ReaderCore = AsconPrfReaderCore;
+
+    fn finalize_xof_core(&mut self, buffer: &mut Buffer<Self>) -> Self::ReaderCore {
+        debug_assert!(buffer.get_pos() < 32);
+        self.state
+            .absorb_last_block(&buffer.get_data()[..buffer.get_pos()]);
+        Self::ReaderCore {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl Reset for AsconPrfCore {
+    fn reset(&mut self) {
+        self.state = self.initial_state.clone();
+    }
+}
+
+impl AlgorithmName for AsconPrfCore {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Ascon-Prf")
+    }
+}
+
+digest::buffer_fixed!(
+    /// Ascon-Mac
+    pub struct AsconMac(AsconMacCore);
+    impl: ResetMacTraits AlgorithmName KeyInit Clone ZeroizeOnDrop;
+);
+
+digest::buffer_xof!(
+    /// Ascon-Prf
+    pub struct AsconPrf(AsconPrfCore);
+    impl: Debug AlgorithmName Clone BlockSizeUser CoreProxy Update Reset ExtendableOutputReset;
+    /// Ascon-Prf reader.
+    pub struct AsconPrfReader(AsconPrfReaderCore);
+    impl: XofReaderTraits;
+);
+
+impl KeySizeUser for AsconPrf {
+    type KeySize = U16;
+}
+
+impl KeyInit for AsconPrf {
+    fn new(key: &Key<Self>) -> Self {
+        Self::compose(AsconPrfCore::new(key), Default::default())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for AsconPrf {}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for AsconPrfReader {}
+
+/// Ascon-PrfShort
+///
+/// Short-input PRF evaluating a single permutation. Inputs and outputs are limited to
+/// [`MAX_PRF_SHORT_INPUT_SIZE`] and [`MAX_PRF_SHORT_OUTPUT_SIZE`] bytes, respectively.
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
+pub struct AsconPrfShort {
+    k0: u64,
+    k1: u64,
+}
+
+impl fmt::Debug for AsconPrfShort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AsconPrfShort { ... }")
+    }
+}
+
+impl KeySizeUser for AsconPrfShort {
+    type KeySize = U16;
+}
+
+impl KeyInit for AsconPrfShort {
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            k0: u64_from_bytes(&key[..8]),
+            k1: u64_from_bytes(&key[8..]),
+        }
+    }
+}
+
+impl AsconPrfShort {
+    /// Evaluate the PRF on `input` and fill `output`.
+    ///
+    /// Fails if `input` is longer than [`MAX_PRF_SHORT_INPUT_SIZE`] bytes or if `output` is
+    /// empty or longer than [`MAX_PRF_SHORT_OUTPUT_SIZE`] bytes.
+    pub fn compute(&self, input: &[u8], output: &mut [u8]) -> Result<(), InvalidLength> {
+        if input.len() > MAX_PRF_SHORT_INPUT_SIZE
+            || output.is_empty()
+            || output.len() > MAX_PRF_SHORT_OUTPUT_SIZE
+        {
+            return Err(InvalidLength);
+        }
+
+        let iv = 0x80
+            | ((8 * input.len() as u64) << 8)
+            | (0x4c << 16)
+            | ((8 * output.len() as u64) << 24);
+        let (m0, m1) = input.split_at(input.len().min(8));
+        let mut state = State::new(
+            iv,
+            self.k0,
+            self.k1,
+            u64_from_bytes_partial(m0),
+            u64_from_bytes_partial(m1),
+        );
+        state.permute_12();
+
+        let mut block = [0u8; 16];
+        block[..8].copy_from_slice(&u64::to_le_bytes(state[3] ^ self.k0));
+        block[8..].copy_from_slice(&u64::to_le_bytes(state[4] ^ self.k1));
+        output.copy_from_slice(&block[..output.len()]);
+        Ok(())
+    }
+
+    /// Verify `tag` for `input` in constant time.
+    pub fn verify(&self, input: &[u8], tag: &[u8]) -> Result<(), MacError> {
+        let mut expected = [0u8; MAX_PRF_SHORT_OUTPUT_SIZE];
+        let expected = &mut expected[..tag.len().min(MAX_PRF_SHORT_OUTPUT_SIZE)];
+        self.compute(input, expected).map_err(|_| MacError)?;
+        if bool::from(expected.ct_eq(tag)) {
+            Ok(())
+        } else {
+            Err(MacError)
+        }
+    }
+}
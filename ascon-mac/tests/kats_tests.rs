@@ -0,0 +1,158 @@
+// Copyright 2026 Sebastian Ramacher
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::HashMap;
+use std::include_str;
+
+use spectral::prelude::{OrderedAssertions, ResultAssertions, asserting};
+
+use ascon_mac::{
+    AsconMac, AsconPrf, AsconPrfShort, ExtendableOutput, KeyInit, Mac, Reset, Update, XofReader,
+};
+
+#[derive(Debug)]
+struct TestVector {
+    count: u32,
+    key: Vec<u8>,
+    message: Vec<u8>,
+    tag: Vec<u8>,
+}
+
+impl TestVector {
+    fn new(count: &str, key: &str, message: &str, tag: &str) -> Self {
+        Self {
+            count: count.parse().unwrap(),
+            key: hex::decode(key).unwrap(),
+            message: hex::decode(message).unwrap(),
+            tag: hex::decode(tag).unwrap(),
+        }
+    }
+}
+
+fn run_tv_mac(tv: TestVector) {
+    let mut mac = AsconMac::new_from_slice(&tv.key).unwrap();
+    Mac::update(&mut mac, &tv.message);
+    let mut mac2 = mac.clone();
+    let tag = mac.finalize().into_bytes();
+    asserting(format!("Test Vector {}: MAC", tv.count).as_str())
+        .that(&tag.as_slice())
+        .is_equal_to(tv.tag.as_slice());
+
+    Mac::reset(&mut mac2);
+    for b in &tv.message {
+        Mac::update(&mut mac2, &[*b]);
+    }
+    asserting(format!("Test Vector {}: After reset", tv.count).as_str())
+        .that(&mac2.clone().verify_slice(&tv.tag))
+        .is_ok();
+
+    let mut bad_tag = tv.tag.clone();
+    bad_tag[0] ^= 1;
+    asserting(format!("Test Vector {}: Invalid tag", tv.count).as_str())
+        .that(&mac2.verify_slice(&bad_tag))
+        .is_err();
+}
+
+fn run_tv_prf(tv: TestVector) {
+    let mut prf = AsconPrf::new_from_slice(&tv.key).unwrap();
+    prf.update(&tv.message);
+    let mut prf2 = prf.clone();
+    let mut reader = prf.finalize_xof();
+    let mut output = vec![0u8; tv.tag.len()];
+    reader.read(output.as_mut_slice());
+    asserting(format!("Test Vector {}: PRF", tv.count).as_str())
+        .that(&output)
+        .is_equal_to(&tv.tag);
+
+    Reset::reset(&mut prf2);
+    for b in &tv.message {
+        prf2.update(&[*b]);
+    }
+    let mut reader = prf2.finalize_xof();
+    for b in output.iter_mut() {
+        reader.read(std::slice::from_mut(b));
+    }
+    asserting(format!("Test Vector {}: After reset", tv.count).as_str())
+        .that(&output)
+        .is_equal_to(&tv.tag);
+}
+
+fn run_tv_prf_short(tv: TestVector) {
+    let prf = AsconPrfShort::new_from_slice(&tv.key).unwrap();
+    let mut output = vec![0u8; tv.tag.len()];
+    prf.compute(&tv.message, &mut output).unwrap();
+    asserting(format!("Test Vector {}: PRF", tv.count).as_str())
+        .that(&output)
+        .is_equal_to(&tv.tag);
+    asserting(format!("Test Vector {}: Verify", tv.count).as_str())
+        .that(&prf.verify(&tv.message, &tv.tag))
+        .is_ok();
+
+    let mut bad_tag = tv.tag.clone();
+    bad_tag[0] ^= 1;
+    asserting(format!("Test Vector {}: Invalid tag", tv.count).as_str())
+        .that(&prf.verify(&tv.message, &bad_tag))
+        .is_err();
+}
+
+fn parse_tvs(tvs: &str) -> Vec<TestVector> {
+    let mut fields: HashMap<&str, &str> = HashMap::new();
+    let mut ret = Vec::new();
+
+    for line in tvs.lines() {
+        if line.starts_with('#') || (line.is_empty() && fields.is_empty()) {
+            continue;
+        }
+        if line.is_empty() && !fields.is_empty() {
+            ret.push(TestVector::new(
+                fields["Count"],
+                fields["Key"],
+                fields["Msg"],
+                fields["Tag"],
+            ));
+            fields.clear();
+            continue;
+        }
+
+        let mut values = line.split(" = ");
+        fields.insert(values.next().unwrap(), values.next().unwrap());
+    }
+
+    asserting!("Test Vectors available")
+        .that(&ret.len())
+        .is_greater_than(0);
+    ret
+}
+
+#[test]
+fn test_vectors_asconmac() {
+    let tvs = parse_tvs(include_str!("data/asconmac.txt"));
+    for tv in tvs {
+        run_tv_mac(tv);
+    }
+}
+
+#[test]
+fn test_vectors_asconprf() {
+    let tvs = parse_tvs(include_str!("data/asconprf.txt"));
+    for tv in tvs {
+        run_tv_prf(tv);
+    }
+}
+
+#[test]
+fn test_vectors_asconprfshort() {
+    let tvs = parse_tvs(include_str!("data/asconprfshort.txt"));
+    for tv in tvs {
+        run_tv_prf_short(tv);
+    }
+}
+
+#[test]
+fn prf_short_invalid_lengths() {
+    let prf = AsconPrfShort::new_from_slice(&[0u8; 16]).unwrap();
+    assert!(prf.compute(&[0u8; 17], &mut [0u8; 16]).is_err());
+    assert!(prf.compute(&[0u8; 16], &mut [0u8; 17]).is_err());
+    assert!(prf.compute(&[0u8; 16], &mut []).is_err());
+    assert!(prf.verify(&[0u8; 16], &[]).is_err());
+}